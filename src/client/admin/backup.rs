@@ -0,0 +1,125 @@
+use futures::{stream, StreamExt, TryStreamExt};
+use tracing::{info, instrument};
+use crate::client::admin::{AdminConnector, BulkItemResult, BulkReport};
+use crate::error::ApisixClientError;
+use crate::models::{BackupArchive, BackupEntry, ResourceKind};
+use crate::Result;
+
+impl AdminConnector {
+    /// Exports every object of every kind of [ResourceKind::ALL] with its `modifiedIndex`,
+    /// including Secrets, Plugin metadata and the Credentials of every Consumer
+    #[instrument(skip(self))]
+    pub async fn backup(&self, concurrency: usize) -> Result<BackupArchive> {
+        let items = stream::iter(ResourceKind::ALL)
+            .map(|kind| async move {
+                let entries = self.list_entries(kind).await?;
+                Ok::<_, ApisixClientError>(entries.into_iter()
+                    .map(move |(id, modified_index, value)| (kind, id, modified_index, value)))
+            })
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+        let archive = BackupArchive::new(&ResourceKind::ALL, items.into_iter().flatten());
+        info!("backup: {} objects", archive.entries.len());
+        Ok(archive)
+    }
+
+    /// Recreates the objects of the archive in dependency order, existing objects with the same id are replaced
+    /// Secrets are restored first, Credentials after their Consumer, see [ResourceKind::APPLY_ORDER]
+    ///
+    /// Objects of the same kind are restored concurrently, kinds one after the other.
+    /// The `modifiedIndex` of the archive is informative, the target cluster assigns its own.
+    #[instrument(skip_all)]
+    pub async fn restore(&self, archive: &BackupArchive, concurrency: usize) -> BulkReport {
+        let mut entries: Vec<&BackupEntry> = archive.entries.iter().collect();
+        entries.sort_by_key(|e| e.kind.apply_rank());
+        let mut items = vec![];
+        for wave in entries.chunk_by(|a, b| a.kind == b.kind) {
+            let results = stream::iter(wave)
+                .map(|entry| async move {
+                    BulkItemResult {
+                        kind: entry.kind,
+                        id: entry.id.clone(),
+                        error: self.put_value(entry.kind, &entry.id, &entry.value).await.err(),
+                    }
+                })
+                .buffered(concurrency.max(1))
+                .collect::<Vec<_>>()
+                .await;
+            items.extend(results);
+        }
+        let report = BulkReport { items };
+        info!("restore: {} succeeded, {} failed", report.succeeded().count(), report.failed().count());
+        report
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::client::test_server::TestServer;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_backup_credentials_secrets_metadata() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/consumers", 200, json!({"total": 2, "list": [
+                {"key": "/apisix/consumers/jack", "modifiedIndex": 3, "value": {"username": "jack"}},
+                {"key": "/apisix/consumers/jill", "modifiedIndex": 4, "value": {"username": "jill"}},
+            ]}))
+            .on("GET", "/apisix/admin/consumers/jack/credentials", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/consumers/jack/credentials/c1", "modifiedIndex": 5, "value": {"id": "c1", "plugins": {"key-auth": {"key": "k1"}}}},
+            ]}))
+            .on("GET", "/apisix/admin/secrets", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/secrets/vault/1", "modifiedIndex": 1, "value": {"id": "vault/1", "uri": "http://vault:8200", "prefix": "kv/apisix", "token": "t"}},
+            ]}))
+            .on("GET", "/apisix/admin/plugin_metadata", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/plugin_metadata/http-logger", "modifiedIndex": 2, "value": {"id": "http-logger", "log_format": {"host": "$host"}}},
+            ]}));
+        let connector = server.connector();
+
+        // every other collection, and the credentials of jill, answer 404
+        let archive = connector.backup(2).await.unwrap();
+        let ids: Vec<(ResourceKind, &str)> = archive.entries.iter().map(|e| (e.kind, e.id.as_str())).collect();
+        assert_eq!(ids, vec![
+            (ResourceKind::secrets, "vault/1"),
+            (ResourceKind::plugin_metadata, "http-logger"),
+            (ResourceKind::consumers, "jack"),
+            (ResourceKind::consumers, "jill"),
+            (ResourceKind::credentials, "jack/credentials/c1"),
+        ]);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_restore_order() {
+        let server = TestServer::start().await;
+        server
+            .on("PUT", "/apisix/admin/routes/r1", 200, json!({}))
+            .on("PUT", "/apisix/admin/consumers/jack/credentials/c1", 200, json!({}))
+            .on("PUT", "/apisix/admin/consumers", 200, json!({}))
+            .on("PUT", "/apisix/admin/secrets/vault/1", 200, json!({}));
+        let archive = BackupArchive::new(&ResourceKind::ALL, [
+            (ResourceKind::routes, "r1".to_string(), None, json!({"id": "r1", "uri": "/a", "plugins": {"key-auth": {}}})),
+            (ResourceKind::credentials, "jack/credentials/c1".to_string(), None, json!({"id": "c1", "plugins": {"key-auth": {"key": "$secret://vault/1/jack/key"}}})),
+            (ResourceKind::consumers, "jack".to_string(), None, json!({"username": "jack", "create_time": 1})),
+            (ResourceKind::secrets, "vault/1".to_string(), None, json!({"id": "vault/1", "uri": "http://vault:8200", "prefix": "kv/apisix", "token": "t"})),
+        ]);
+        let connector = server.connector();
+
+        let report = connector.restore(&archive, 4).await;
+        assert!(report.is_success(), "{:?}", report);
+        assert_eq!(server.calls(), vec![
+            "PUT /apisix/admin/secrets/vault/1",
+            "PUT /apisix/admin/consumers",
+            "PUT /apisix/admin/consumers/jack/credentials/c1",
+            "PUT /apisix/admin/routes/r1",
+        ]);
+        assert_eq!(server.requests()[1].body, Some(json!({"username": "jack"})));
+    }
+}
+// endregion: tests
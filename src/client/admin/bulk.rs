@@ -0,0 +1,510 @@
+use std::collections::{HashMap, HashSet};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde_json::Value;
+use tracing::{info, instrument, warn};
+use crate::client::admin::{path_consumer_credentials, path_kind, AdminConnector, AdminResource};
+use crate::client::reqwest_generic::put;
+use crate::error::ApisixClientError;
+use crate::models::common::TypedItem;
+use crate::models::{sort_for_apply, sort_for_delete, AdminObject, LabelSelector, ListQuery, ResourceKind, StandaloneConfig, SERVER_MANAGED_FIELDS};
+use crate::Result;
+
+/// Outcome of a single object of a bulk operation
+#[derive(Debug)]
+pub struct BulkItemResult {
+    pub kind: ResourceKind,
+    pub id: String,
+    pub error: Option<ApisixClientError>,
+}
+
+impl BulkItemResult {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Per object report of a bulk operation, in the order of the input
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    pub items: Vec<BulkItemResult>,
+}
+
+impl BulkReport {
+    /// True when every object succeeded
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(BulkItemResult::is_success)
+    }
+
+    pub fn succeeded(&self) -> impl Iterator<Item = &BulkItemResult> {
+        self.items.iter().filter(|i| i.is_success())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &BulkItemResult> {
+        self.items.iter().filter(|i| !i.is_success())
+    }
+}
+
+impl AdminConnector {
+    /// Creates or replaces the object
+    pub async fn apply_object(&self, object: &AdminObject) -> Result<()> {
+        let id = object_id(object)?;
+        match object {
+            AdminObject::Consumer(req) => self.consumers().put(id, req).await.map(|_| ()),
+            _ => self.resource(object.kind()).put(id, &object.to_value()?).await.map(|_| ()),
+        }
+    }
+
+    /// Creates or replaces the objects, at most `concurrency` requests are in flight
+    #[instrument(skip(self, objects))]
+    pub async fn bulk_apply(&self, objects: &[AdminObject], concurrency: usize) -> BulkReport {
+        let items = stream::iter(objects)
+            .map(|object| async move {
+                BulkItemResult {
+                    kind: object.kind(),
+                    id: object.id().unwrap_or_default().to_string(),
+                    error: self.apply_object(object).await.err(),
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        report("apply", items)
+    }
+
+    /// Removes the objects, a missing object counts as success
+    #[instrument(skip(self, targets))]
+    pub async fn bulk_delete(&self, targets: &[(ResourceKind, String)], concurrency: usize) -> BulkReport {
+        let items = stream::iter(targets)
+            .map(|(kind, id)| async move {
+                BulkItemResult {
+                    kind: *kind,
+                    id: id.clone(),
+                    error: self.resource(*kind).delete_if_exists(id).await.err(),
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        report("delete", items)
+    }
+
+    /// Creates or replaces the objects in dependency order, see [ResourceKind::APPLY_ORDER]
+    ///
+    /// Objects of the same kind are applied concurrently, kinds one after the other.
+    /// An object referencing an object that failed is not sent and reported as failed.
+    /// The report follows the order in which the objects were applied.
+    #[instrument(skip(self, objects))]
+    pub async fn apply_ordered(&self, objects: &[AdminObject], concurrency: usize) -> BulkReport {
+        let mut objects = objects.to_vec();
+        sort_for_apply(&mut objects);
+        let mut failed: HashSet<(ResourceKind, String)> = HashSet::new();
+        let mut items = vec![];
+        for wave in objects.chunk_by(|a, b| a.kind() == b.kind()) {
+            let (blocked, ready): (Vec<&AdminObject>, Vec<&AdminObject>) = wave.iter()
+                .partition(|o| o.references().iter().any(|r| failed.contains(r)));
+            let ready: Vec<AdminObject> = ready.into_iter().cloned().collect();
+            let mut wave_items = self.bulk_apply(&ready, concurrency).await.items;
+            wave_items.extend(blocked.into_iter().map(|o| BulkItemResult {
+                kind: o.kind(),
+                id: o.id().unwrap_or_default().to_string(),
+                error: Some(ApisixClientError::InvalidRequest(format!("a dependency failed: {:?}", o.references()))),
+            }));
+            failed.extend(wave_items.iter().filter(|i| !i.is_success()).map(|i| (i.kind, i.id.clone())));
+            items.extend(wave_items);
+        }
+        BulkReport { items }
+    }
+
+    /// Removes the objects in reverse dependency order: Routes before Services before Upstreams, ...
+    /// Objects of the same kind are removed concurrently, kinds one after the other.
+    #[instrument(skip(self, targets))]
+    pub async fn delete_ordered(&self, targets: &[(ResourceKind, String)], concurrency: usize) -> BulkReport {
+        let mut targets = targets.to_vec();
+        sort_for_delete(&mut targets);
+        let mut items = vec![];
+        for wave in targets.chunk_by(|a, b| a.0 == b.0) {
+            items.extend(self.bulk_delete(wave, concurrency).await.items);
+        }
+        BulkReport { items }
+    }
+
+    /// Removes the object after the Routes and Stream Routes using it, see [CASCADE_REFERENCES]
+    ///
+    /// Fails with [ApisixClientError::Conflict] without removing anything when an object is still
+    /// referencing it otherwise, e.g. a Service using the Upstream or a Consumer in the Consumer group.
+    #[instrument(skip(self))]
+    pub async fn delete_cascade(&self, kind: ResourceKind, id: &str, concurrency: usize) -> Result<BulkReport> {
+        let mut targets = vec![];
+        let mut blocking = vec![];
+        for (referrer, pointer) in kind.referenced_by() {
+            for (referrer_id, _, value) in self.list_entries(referrer).await? {
+                if value.pointer(pointer).and_then(id_string).as_deref() != Some(id) {
+                    continue;
+                }
+                if CASCADE_REFERENCES.contains(&(referrer, pointer)) {
+                    info!("{}/{} references {}/{}, deleting it first", referrer, referrer_id, kind, id);
+                    targets.push((referrer, referrer_id));
+                } else {
+                    blocking.push(format!("{}/{}", referrer, referrer_id));
+                }
+            }
+        }
+        if !blocking.is_empty() {
+            return Err(ApisixClientError::Conflict {
+                status: None,
+                message: format!("still referenced by {}", blocking.join(", ")),
+                path: format!("{}/{}", path_kind(kind), id),
+                error: None,
+            });
+        }
+        targets.push((kind, id.to_string()));
+        Ok(self.delete_ordered(&targets, concurrency).await)
+    }
+
+    /// Fetches all the objects of the specified kinds, one kind per request in flight up to `concurrency`
+    ///
+    /// Fails when an object holds plugins or attributes that are not modelled by this crate, see
+    /// [AdminObject::from_value]; [AdminConnector::backup] copies the objects as stored instead.
+    #[instrument(skip(self))]
+    pub async fn bulk_export(&self, kinds: &[ResourceKind], concurrency: usize) -> Result<Vec<AdminObject>> {
+        let exported = stream::iter(kinds)
+            .map(|kind| async move {
+                let entries = self.list_entries(*kind).await?;
+                entries.into_iter()
+                    .map(|(_, _, v)| AdminObject::from_value(*kind, v))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(exported.into_iter().flatten().collect())
+    }
+
+    /// Fetches all the objects of the [StandaloneConfig::KINDS] as a standalone configuration (`conf/apisix.yaml`)
+    ///
+    /// Objects are copied as stored, plugins not modelled by this crate included, without the
+    /// [SERVER_MANAGED_FIELDS]. Secrets and Credentials are not exported.
+    #[instrument(skip(self))]
+    pub async fn export_standalone(&self, concurrency: usize) -> Result<StandaloneConfig> {
+        let sections = stream::iter(StandaloneConfig::KINDS)
+            .map(|kind| async move {
+                let values: Vec<Value> = self.list_entries(kind).await?
+                    .into_iter()
+                    .map(|(id, _, value)| {
+                        let mut value = without_server_fields(&value);
+                        if let (ResourceKind::plugin_metadata, Some(fields)) = (kind, value.as_object_mut()) {
+                            fields.entry("id").or_insert(Value::String(id));
+                        }
+                        value
+                    })
+                    .collect();
+                Ok::<_, ApisixClientError>((kind, values))
+            })
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+        let mut config = StandaloneConfig::default();
+        for (kind, values) in sections.into_iter().filter(|(_, values)| !values.is_empty()) {
+            if let Some(section) = config.section_mut(kind) {
+                *section = Some(values);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Removes all the objects of the kind whose labels match the selector
+    #[instrument(skip(self))]
+    pub async fn delete_selected(&self, kind: ResourceKind, selector: &LabelSelector, concurrency: usize) -> Result<BulkReport> {
+        let targets: Vec<(ResourceKind, String)> = self.list_entries(kind).await?
+            .into_iter()
+            .filter(|(_, _, v)| selector.matches(value_labels(v).as_ref()))
+            .map(|(id, _, _)| (kind, id))
+            .collect();
+        Ok(self.bulk_delete(&targets, concurrency).await)
+    }
+
+    /// Removes all the objects of the kind whose id starts with the prefix, e.g. `gen-`
+    ///
+    /// The id of a Credential is `{username}/credentials/{id}`, see [ResourceKind::id_from_key].
+    #[instrument(skip(self))]
+    pub async fn delete_by_id_prefix(&self, kind: ResourceKind, prefix: &str, concurrency: usize) -> Result<BulkReport> {
+        let targets: Vec<(ResourceKind, String)> = self.list_entries(kind).await?
+            .into_iter()
+            .map(|(id, _, _)| id)
+            .filter(|id| id.starts_with(prefix))
+            .map(|id| (kind, id))
+            .collect();
+        Ok(self.bulk_delete(&targets, concurrency).await)
+    }
+
+    /// Creates or replaces the object from its raw value, attributes not modelled by this crate are kept
+    /// The [SERVER_MANAGED_FIELDS] are dropped before sending.
+    pub(crate) async fn put_value(&self, kind: ResourceKind, id: &str, value: &Value) -> Result<()> {
+        let value = without_server_fields(value);
+        match kind {
+            ResourceKind::consumers => {
+                let url = format!("{}{}", self.cfg.admin_url, path_kind(kind));
+                put::<Value, Value>(&self.http, url.as_str(), self.cfg.admin_apikey.as_str(), &value).await.map(|_| ())
+            },
+            _ => self.resource(kind).put(id, &value).await.map(|_| ()),
+        }
+    }
+
+    /// Raw objects of the kind with their id and `modifiedIndex`, a collection the gateway does not list (404) is empty
+    ///
+    /// Credentials are listed Consumer by Consumer, their id is `{username}/credentials/{id}`.
+    pub(crate) async fn list_entries(&self, kind: ResourceKind) -> Result<Vec<(String, Option<i64>, Value)>> {
+        let paths = match kind {
+            ResourceKind::credentials => self.list_path(ResourceKind::consumers, path_kind(ResourceKind::consumers)).await?
+                .into_iter()
+                .map(|(username, _, _)| path_consumer_credentials(&username))
+                .collect(),
+            _ => vec![path_kind(kind)],
+        };
+        let mut entries = vec![];
+        for path in paths {
+            entries.extend(self.list_path(kind, path).await?);
+        }
+        Ok(entries)
+    }
+
+    async fn list_path(&self, kind: ResourceKind, path: String) -> Result<Vec<(String, Option<i64>, Value)>> {
+        let resource: AdminResource<Value, Value> = AdminResource::new(self, path);
+        let items: Vec<TypedItem<Value>> = match resource.list_stream(&ListQuery::default()).try_collect().await {
+            Err(e) if e.is_not_found() => vec![],
+            items => items?,
+        };
+        Ok(items.into_iter()
+            .filter_map(|item| {
+                let value = item.value?;
+                let id = item.key.as_deref().and_then(|key| kind.id_from_key(key)).or_else(|| value_id(kind, &value))?;
+                Some((id, item.modified_index, value))
+            })
+            .collect())
+    }
+}
+
+/// References followed by [AdminConnector::delete_cascade]: (referencing kind, JSON pointer of the reference)
+pub const CASCADE_REFERENCES: [(ResourceKind, &str); 4] = [
+    (ResourceKind::routes, "/upstream_id"),
+    (ResourceKind::routes, "/service_id"),
+    (ResourceKind::stream_routes, "/upstream_id"),
+    (ResourceKind::stream_routes, "/service_id"),
+];
+
+/// Copy of a raw object without the fields set by Apisix, which are rejected on write
+pub(crate) fn without_server_fields(value: &Value) -> Value {
+    let mut value = value.clone();
+    if let Some(fields) = value.as_object_mut() {
+        SERVER_MANAGED_FIELDS.iter().for_each(|f| { fields.remove(*f); });
+    }
+    value
+}
+
+fn object_id(object: &AdminObject) -> Result<&str> {
+    object.id().ok_or(ApisixClientError::InvalidRequest(format!("{} object without {}", object.kind(), object.kind().id_field())))
+}
+
+/// Id of a raw object, numeric ids are turned into strings
+pub(crate) fn value_id(kind: ResourceKind, value: &Value) -> Option<String> {
+    value.get(kind.id_field()).and_then(id_string)
+}
+
+/// Ids can be returned as strings or numbers
+pub(crate) fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+pub(crate) fn value_labels(value: &Value) -> Option<HashMap<String, String>> {
+    value.get("labels").and_then(|l| serde_json::from_value(l.clone()).ok())
+}
+
+fn report(operation: &str, items: Vec<BulkItemResult>) -> BulkReport {
+    let report = BulkReport { items };
+    for failed in report.failed() {
+        warn!("bulk {} failed for {}/{}: {:?}", operation, failed.kind, failed.id, failed.error);
+    }
+    info!("bulk {}: {} succeeded, {} failed", operation, report.succeeded().count(), report.failed().count());
+    report
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::client::test_server::TestServer;
+    use crate::models::UpstreamBuilder;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_value_id_and_labels() {
+        let consumer = serde_json::json!({"username": "jack", "labels": {"env": "test"}});
+        assert_eq!(value_id(ResourceKind::consumers, &consumer).unwrap(), "jack");
+        assert_eq!(value_id(ResourceKind::routes, &serde_json::json!({"id": 12})).unwrap(), "12");
+        let selector: LabelSelector = "env=test".parse().unwrap();
+        assert!(selector.matches(value_labels(&consumer).as_ref()));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_bulk_report() {
+        let report = BulkReport {
+            items: vec![
+                BulkItemResult { kind: ResourceKind::routes, id: "r1".to_string(), error: None },
+                BulkItemResult { kind: ResourceKind::routes, id: "r2".to_string(), error: Some(ApisixClientError::InvalidRequest("boom".to_string())) },
+            ],
+        };
+        assert!(!report.is_success());
+        assert_eq!(report.failed().next().unwrap().id, "r2");
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_bulk_apply_concurrency_and_order() {
+        let server = TestServer::start().await;
+        server.with_delay(Duration::from_millis(30));
+        // the first object is answered last
+        server.with_path_delay("/apisix/admin/upstreams/u0", Duration::from_millis(150));
+        let ids: Vec<String> = (0..6).map(|i| format!("u{}", i)).collect();
+        for id in &ids {
+            let status = if id == "u3" { 500 } else { 200 };
+            server.on("PUT", &format!("/apisix/admin/upstreams/{}", id), status, json!({"value": {"id": id}}));
+        }
+        let objects: Vec<AdminObject> = ids.iter()
+            .map(|id| UpstreamBuilder::new().with_id(id.clone()).build().unwrap().into())
+            .collect();
+        let connector = server.connector();
+
+        let report = connector.bulk_apply(&objects, 2).await;
+        assert_eq!(server.max_in_flight(), 2);
+        assert_eq!(report.items.iter().map(|i| i.id.clone()).collect::<Vec<_>>(), ids);
+        assert_eq!(report.failed().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["u3"]);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_bulk_delete_concurrency_and_order() {
+        let server = TestServer::start().await;
+        server.with_delay(Duration::from_millis(30));
+        server.with_path_delay("/apisix/admin/routes/r0", Duration::from_millis(150));
+        let targets: Vec<(ResourceKind, String)> = (0..7).map(|i| (ResourceKind::routes, format!("r{}", i))).collect();
+        for (_, id) in targets.iter().filter(|(_, id)| id != "r5") {
+            server.on("DELETE", &format!("/apisix/admin/routes/{}", id), 200, json!({"deleted": "1"}));
+        }
+        let connector = server.connector();
+
+        // r5 is missing, which counts as deleted
+        let report = connector.bulk_delete(&targets, 3).await;
+        assert_eq!(server.max_in_flight(), 3);
+        assert!(report.is_success());
+        assert_eq!(report.items.iter().map(|i| (i.kind, i.id.clone())).collect::<Vec<_>>(), targets);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_export_standalone_keeps_unmodelled_plugins() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/routes", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/routes/r1", "value": {"id": "r1", "uri": "/a", "create_time": 1, "plugins": {"cors": {"allow_origins": "*"}}}},
+            ]}))
+            .on("GET", "/apisix/admin/plugin_metadata", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/plugin_metadata/http-logger", "value": {"log_format": {"host": "$host"}}},
+            ]}));
+        let connector = server.connector();
+
+        // every other collection answers 404
+        let config = connector.export_standalone(2).await.unwrap();
+        assert_eq!(config.routes, Some(vec![json!({"id": "r1", "uri": "/a", "plugins": {"cors": {"allow_origins": "*"}}})]));
+        assert_eq!(config.plugin_metadata, Some(vec![json!({"id": "http-logger", "log_format": {"host": "$host"}})]));
+        assert!(config.upstreams.is_none());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_credentials_collection() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/consumers", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/consumers/jack", "value": {"username": "jack", "labels": {"env": "test"}}},
+            ]}))
+            .on("GET", "/apisix/admin/consumers/jack/credentials", 200, json!({"total": 2, "list": [
+                {"key": "/apisix/consumers/jack/credentials/c1", "value": {"id": "c1", "labels": {"env": "test"}}},
+                {"key": "/apisix/consumers/jack/credentials/c2", "value": {"id": "c2"}},
+            ]}))
+            .on("DELETE", "/apisix/admin/consumers/jack/credentials/c1", 200, json!({}));
+        let connector = server.connector();
+
+        let error = connector.resource(ResourceKind::credentials).list().await.unwrap_err();
+        assert!(matches!(error, ApisixClientError::InvalidRequest(_)), "{:?}", error);
+        assert!(server.requests().is_empty());
+
+        let selector: LabelSelector = "env=test".parse().unwrap();
+        let report = connector.delete_selected(ResourceKind::credentials, &selector, 2).await.unwrap();
+        assert!(report.is_success(), "{:?}", report);
+        assert_eq!(report.items.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["jack/credentials/c1"]);
+        let deletes: Vec<String> = server.calls().into_iter().filter(|c| c.starts_with("DELETE")).collect();
+        assert_eq!(deletes, vec!["DELETE /apisix/admin/consumers/jack/credentials/c1"]);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_delete_cascade() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/routes", 200, json!({"total": 2, "list": [
+                {"key": "/apisix/routes/r1", "value": {"id": "r1", "uri": "/a", "upstream_id": "u1"}},
+                {"key": "/apisix/routes/r2", "value": {"id": "r2", "uri": "/b", "upstream_id": "u2"}},
+            ]}))
+            .on("GET", "/apisix/admin/stream_routes", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/stream_routes/s1", "value": {"id": "s1", "upstream_id": "u1"}},
+            ]}))
+            .on("GET", "/apisix/admin/services", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/services/svc", "value": {"id": "svc", "upstream_id": "u2"}},
+            ]}))
+            .on("DELETE", "/apisix/admin/routes/r1", 200, json!({}))
+            .on("DELETE", "/apisix/admin/stream_routes/s1", 200, json!({}))
+            .on("DELETE", "/apisix/admin/upstreams/u1", 200, json!({}));
+        let connector = server.connector();
+
+        let report = connector.delete_cascade(ResourceKind::upstreams, "u1", 2).await.unwrap();
+        assert!(report.is_success(), "{:?}", report);
+        let deletes: Vec<String> = server.calls().into_iter().filter(|c| c.starts_with("DELETE")).collect();
+        assert_eq!(deletes, vec![
+            "DELETE /apisix/admin/stream_routes/s1",
+            "DELETE /apisix/admin/routes/r1",
+            "DELETE /apisix/admin/upstreams/u1",
+        ]);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_delete_cascade_still_referenced() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/routes", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/routes/r1", "value": {"id": "r1", "uri": "/a", "upstream_id": "u1"}},
+            ]}))
+            .on("GET", "/apisix/admin/services", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/services/svc", "value": {"id": "svc", "upstream_id": "u1"}},
+            ]}))
+            .on("GET", "/apisix/admin/consumers", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/consumers/jack", "value": {"username": "jack", "group_id": "g1"}},
+            ]}));
+        let connector = server.connector();
+
+        let error = connector.delete_cascade(ResourceKind::upstreams, "u1", 2).await.unwrap_err();
+        assert!(error.is_conflict(), "{:?}", error);
+        let error = connector.delete_cascade(ResourceKind::consumer_groups, "g1", 2).await.unwrap_err();
+        assert!(error.is_conflict(), "{:?}", error);
+        assert!(server.requests().iter().all(|r| r.method == "GET"));
+    }
+}
+// endregion: tests
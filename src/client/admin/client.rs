@@ -3,185 +3,137 @@
 //! The Admin API lets users control their deployed Apache APISIX instance.
 //! The architecture design gives an idea about how everything fits together.
 
-use anyhow::Result;
-use reqwest::Response;
-use tracing::{debug, instrument};
-use crate::client::admin::{path_check_version, path_consumer, path_consumer_group_with_id, path_consumer_groups, path_consumer_with_id, path_route_with_id, path_routes, path_service_with_id, path_services, path_upstream_with_id, path_upstreams};
-use crate::client::reqwest_generic::{delete, get, head, put};
+use std::path::Path;
+use reqwest::Client;
+use tracing::{debug, info, instrument};
+use crate::client::admin::{path_check_version, path_consumer_groups, path_global_rules, path_kind, path_plugin_configs, path_protos, path_routes, path_secrets, path_services, path_ssls, path_stream_routes, path_upstreams, AdminResource, ConsumerResource, PluginMetadataResource};
+use crate::client::reqwest_generic::head;
 use crate::config::ApisixConfig;
-use crate::models::{ApisixConsumer, ApisixConsumerGroup, ApisixRoute, ApisixService, ApisixUpstream, ConsumerGroupRequest, ConsumerRequest, RouteRequest, ServiceRequest, UpstreamRequest};
-use crate::models::common::{ListResponse, TypedItem};
+use crate::error::ApisixClientError;
+use crate::Result;
+use serde_json::Value;
+use crate::models::{ResourceKind, ApisixAwsSecret, ApisixConsumerGroup, ApisixProto, ProtoBuilder, ProtoRequest, ApisixGlobalRule, ApisixPluginConfig, ApisixRoute, ApisixService, ApisixSsl, ApisixStreamRoute, ApisixUpstream, ApisixVaultSecret, AwsSecretRequest, ConsumerGroupRequest, GlobalRuleRequest, PluginConfigRequest, RouteRequest, ServiceRequest, SslRequest, StreamRouteRequest, UpstreamRequest, SecretManager, VaultSecretRequest};
 
-#[derive(Debug, Default)]
+/// Admin API sub-client, obtained through [crate::client::ApisixClient::admin]
+#[derive(Debug, Default, Clone)]
 pub struct AdminConnector {
     pub cfg: ApisixConfig,
+    pub(crate) http: Client,
 }
 
 impl AdminConnector {
 
-    #[instrument]
-    pub (crate) async fn new(cfg: &ApisixConfig) -> Self {
+    pub (crate) fn new(cfg: &ApisixConfig, http: Client) -> Self {
         AdminConnector {
             cfg: cfg.clone(),
+            http,
         }
     }
 
     #[instrument(skip(self))]
-    pub (crate) async fn check_version(&self) -> Result<()> {
+    pub async fn check_version(&self) -> Result<()> {
         let path = format!("{}{}", self.cfg.admin_url, path_check_version());
         debug!("admin_api::check_version: {}", path);
-        debug!("admin_api::get_certificate_by_document_number::body {:#?}", "None");
-        head(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+        head(&self.http, path.as_str(), self.cfg.admin_apikey.as_str()).await
+    }
+
+    /// Untyped resource of the specified kind, objects are handled as [serde_json::Value]
+    ///
+    /// Credentials are reached by their id `{username}/credentials/{id}` and cannot be listed from there,
+    /// see [ConsumerResource::credentials].
+    pub fn resource(&self, kind: ResourceKind) -> AdminResource<'_, Value, Value> {
+        let resource = AdminResource::new(self, path_kind(kind));
+        match kind {
+            ResourceKind::credentials => resource.unlisted(),
+            _ => resource,
+        }
     }
 
-    // region: upstream api
-    #[instrument(skip(self))]
-    pub (crate) async fn get_upstreams(&self) -> Result<ListResponse<TypedItem<ApisixUpstream>>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_upstreams());
-        debug!("admin_api::get_upstreams: {}", path);
-        get::<ListResponse<TypedItem<ApisixUpstream>>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Upstreams resource (`/apisix/admin/upstreams`)
+    pub fn upstreams(&self) -> AdminResource<'_, UpstreamRequest, ApisixUpstream> {
+        AdminResource::new(self, path_upstreams())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn get_upstream(&self, id: &str) -> Result<TypedItem<ApisixUpstream>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_upstream_with_id(id));
-        debug!("admin_api::get_upstream: {}", path);
-        get::<TypedItem<ApisixUpstream>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Services resource (`/apisix/admin/services`)
+    pub fn services(&self) -> AdminResource<'_, ServiceRequest, ApisixService> {
+        AdminResource::new(self, path_services())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn create_upstream_with_id(&self, id: &str, req: &UpstreamRequest) -> Result<TypedItem<ApisixUpstream>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_upstream_with_id(id));
-        debug!("admin_api::create_upstream_with_id: {}", path);
-        put::<UpstreamRequest, TypedItem<ApisixUpstream>>(path.as_str(), self.cfg.admin_apikey.as_str(), req, self.cfg.client_request_timeout).await
+    /// Routes resource (`/apisix/admin/routes`)
+    pub fn routes(&self) -> AdminResource<'_, RouteRequest, ApisixRoute> {
+        AdminResource::new(self, path_routes())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn delete_upstream(&self, id: &str) -> Result<Response> {
-        let path = format!("{}{}", self.cfg.admin_url, path_upstream_with_id(id));
-        debug!("admin_api::delete_upstream: {}", path);
-        delete(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Consumer groups resource (`/apisix/admin/consumer_groups`)
+    pub fn consumer_groups(&self) -> AdminResource<'_, ConsumerGroupRequest, ApisixConsumerGroup> {
+        AdminResource::new(self, path_consumer_groups())
     }
-    // endregion: upstream api
 
-    // region: service api
-    #[instrument(skip(self))]
-    pub (crate) async fn get_services(&self) -> Result<ListResponse<TypedItem<ApisixService>>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_services());
-        debug!("admin_api::get_services: {}", path);
-        get::<ListResponse<TypedItem<ApisixService>>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Consumers resource (`/apisix/admin/consumers`), keyed by username
+    pub fn consumers(&self) -> ConsumerResource<'_> {
+        ConsumerResource::new(self)
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn get_service(&self, id: &str) -> Result<TypedItem<ApisixService>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_service_with_id(id));
-        debug!("admin_api::get_service: {}", path);
-        get::<TypedItem<ApisixService>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// SSL certificates resource (`/apisix/admin/ssls`)
+    pub fn ssls(&self) -> AdminResource<'_, SslRequest, ApisixSsl> {
+        AdminResource::new(self, path_ssls())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn create_service_with_id(&self, id: &str, req: &ServiceRequest) -> Result<TypedItem<ApisixService>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_service_with_id(id));
-        debug!("admin_api::create_service_with_id: {}", path);
-        put::<ServiceRequest, TypedItem<ApisixService>>(path.as_str(), self.cfg.admin_apikey.as_str(), req, self.cfg.client_request_timeout).await
+    /// Global rules resource (`/apisix/admin/global_rules`)
+    pub fn global_rules(&self) -> AdminResource<'_, GlobalRuleRequest, ApisixGlobalRule> {
+        AdminResource::new(self, path_global_rules())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn delete_service(&self, id: &str) -> Result<Response> {
-        let path = format!("{}{}", self.cfg.admin_url, path_service_with_id(id));
-        debug!("admin_api::delete_service: {}", path);
-        delete(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Plugin configs resource (`/apisix/admin/plugin_configs`)
+    pub fn plugin_configs(&self) -> AdminResource<'_, PluginConfigRequest, ApisixPluginConfig> {
+        AdminResource::new(self, path_plugin_configs())
     }
-    // endregion: service api
 
-    // region: route api
-    #[instrument(skip(self))]
-    pub (crate) async fn get_routes(&self) -> Result<ListResponse<TypedItem<ApisixRoute>>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_routes());
-        debug!("admin_api::get_routes: {}", path);
-        get::<ListResponse<TypedItem<ApisixRoute>>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Stream routes resource (`/apisix/admin/stream_routes`)
+    pub fn stream_routes(&self) -> AdminResource<'_, StreamRouteRequest, ApisixStreamRoute> {
+        AdminResource::new(self, path_stream_routes())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn get_route(&self, id: &str) -> Result<TypedItem<ApisixRoute>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_route_with_id(id));
-        debug!("admin_api::get_route: {}", path);
-        get::<TypedItem<ApisixRoute>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Vault secret managers resource (`/apisix/admin/secrets/vault`)
+    pub fn vault_secrets(&self) -> AdminResource<'_, VaultSecretRequest, ApisixVaultSecret> {
+        AdminResource::new(self, path_secrets(&SecretManager::vault))
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn create_route_with_id(&self, id: &str, req: &RouteRequest) -> Result<TypedItem<ApisixRoute>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_route_with_id(id));
-        debug!("admin_api::create_route_with_id: {}", path);
-        put::<RouteRequest, TypedItem<ApisixRoute>>(path.as_str(), self.cfg.admin_apikey.as_str(), req, self.cfg.client_request_timeout).await
+    /// AWS secret managers resource (`/apisix/admin/secrets/aws`)
+    pub fn aws_secrets(&self) -> AdminResource<'_, AwsSecretRequest, ApisixAwsSecret> {
+        AdminResource::new(self, path_secrets(&SecretManager::aws))
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn delete_route(&self, id: &str) -> Result<Response> {
-        let path = format!("{}{}", self.cfg.admin_url, path_route_with_id(id));
-        debug!("admin_api::delete_route: {}", path);
-        delete(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Plugin metadata resource (`/apisix/admin/plugin_metadata`)
+    pub fn plugin_metadata(&self) -> PluginMetadataResource<'_> {
+        PluginMetadataResource::new(self)
     }
-    // endregion: route api
 
-    // region: consumer group api
-    #[instrument(skip(self))]
-    pub (crate) async fn get_consumer_groups(&self) -> Result<ListResponse<TypedItem<ApisixConsumerGroup>>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_groups());
-        debug!("admin_api::get_consumer_groups: {}", path);
-        get::<ListResponse<TypedItem<ApisixConsumerGroup>>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Protos resource (`/apisix/admin/protos`)
+    pub fn protos(&self) -> AdminResource<'_, ProtoRequest, ApisixProto> {
+        AdminResource::new(self, path_protos())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn get_consumer_group(&self, id: &str) -> Result<TypedItem<ApisixConsumerGroup>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_group_with_id(id));
-        debug!("admin_api::get_consumer_group: {}", path);
-        get::<TypedItem<ApisixConsumerGroup>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Uploads a `.proto` file, or a compiled descriptor set, using the file stem as id
+    /// Returns the id to use as `proto_id` of the grpc-transcode plugin
+    #[instrument(skip(self, path))]
+    pub async fn upload_proto(&self, path: impl AsRef<Path>) -> Result<String> {
+        let path = path.as_ref();
+        let id = path.file_stem().and_then(|s| s.to_str())
+            .ok_or(ApisixClientError::InvalidRequest(format!("invalid proto file name: {}", path.display())))?;
+        let req = ProtoBuilder::new().with_id(id.to_string()).with_file(path)?.build()?;
+        self.protos().put(id, &req).await?;
+        info!("Proto {} uploaded as {}", path.display(), id);
+        Ok(id.to_string())
     }
 
-    #[instrument(skip(self))]
-    pub (crate) async fn create_consumer_group_with_id(&self, id: &str, req: &ConsumerGroupRequest) -> Result<TypedItem<ApisixConsumerGroup>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_group_with_id(id));
-        debug!("admin_api::create_consumer_group_with_id: {}", path);
-        put::<ConsumerGroupRequest, TypedItem<ApisixConsumerGroup>>(path.as_str(), self.cfg.admin_apikey.as_str(), req, self.cfg.client_request_timeout).await
-    }
-
-    #[instrument(skip(self))]
-    pub (crate) async fn delete_consumer_group(&self, id: &str) -> Result<Response> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_group_with_id(id));
-        debug!("admin_api::delete_consumer_group: {}", path);
-        delete(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
-    }
-    // endregion: consumer group api
-
-    // region: consumer api
-    #[instrument(skip(self))]
-    pub (crate) async fn get_consumers(&self) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer());
-        debug!("admin_api::get_consumers: {}", path);
-        get::<ListResponse<TypedItem<ApisixConsumer>>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
-    }
-
-    #[instrument(skip(self))]
-    pub (crate) async fn get_consumer(&self, id: &str) -> Result<TypedItem<ApisixConsumer>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_with_id(id));
-        debug!("admin_api::get_consumer: {}", path);
-        get::<TypedItem<ApisixConsumer>>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
-    }
-
-    #[instrument(skip(self))]
-    pub (crate) async fn create_consumer(&self, id: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer());
-        debug!("admin_api::create_consumer: {}", path);
-        put::<ConsumerRequest, TypedItem<ApisixConsumer>>(path.as_str(), self.cfg.admin_apikey.as_str(), req, self.cfg.client_request_timeout).await
-    }
-
-    #[instrument(skip(self))]
-    pub (crate) async fn delete_consumer(&self, id: &str) -> Result<Response> {
-        let path = format!("{}{}", self.cfg.admin_url, path_consumer_with_id(id));
-        debug!("admin_api::delete_consumer: {}", path);
-        delete(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+    /// Uploads several `.proto` files or descriptor sets, see [AdminConnector::upload_proto]
+    pub async fn upload_protos<P: AsRef<Path>>(&self, paths: &[P]) -> Result<Vec<String>> {
+        let mut ids = Vec::with_capacity(paths.len());
+        for path in paths {
+            ids.push(self.upload_proto(path).await?);
+        }
+        Ok(ids)
     }
-    // endregion: consumer api
 
-}
\ No newline at end of file
+}
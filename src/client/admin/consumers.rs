@@ -0,0 +1,278 @@
+use futures::Stream;
+use serde_json::Value;
+use tracing::{debug, info, instrument, warn};
+use crate::client::admin::bulk::{id_string, without_server_fields};
+use crate::client::admin::{path_consumer, path_consumer_credentials, AdminConnector, AdminResource};
+use crate::client::reqwest_generic::put;
+use crate::error::ApisixClientError;
+use crate::models::common::{ListResponse, TypedItem};
+use crate::models::{ApisixConsumer, ApisixCredential, ConsumerRequest, CredentialBuilder, CredentialRequest, LabelSelector, ListQuery, ResourceKind};
+use crate::Result;
+
+/// Consumers resource (`/apisix/admin/consumers`)
+///
+/// Consumers are keyed by `username` and are created with a PUT on the collection,
+/// the username being part of the body.
+#[derive(Debug)]
+pub struct ConsumerResource<'a> {
+    connector: &'a AdminConnector,
+    inner: AdminResource<'a, ConsumerRequest, ApisixConsumer>,
+}
+
+impl<'a> ConsumerResource<'a> {
+    pub(crate) fn new(connector: &'a AdminConnector) -> Self {
+        ConsumerResource {
+            connector,
+            inner: AdminResource::new(connector, path_consumer()),
+        }
+    }
+
+    /// Path of the collection, relative to the admin url
+    pub fn path(&self) -> &str {
+        self.inner.path()
+    }
+
+    /// Fetches all the Consumers
+    pub async fn list(&self) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
+        self.inner.list().await
+    }
+
+    /// Fetches the Consumers matching the query, see [ListQuery]
+    pub async fn list_with(&self, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
+        self.inner.list_with(query).await
+    }
+
+    /// Walks all the pages of Consumers matching the query
+    pub fn list_stream(&self, query: &ListQuery) -> impl Stream<Item = Result<TypedItem<ApisixConsumer>>> + '_ {
+        self.inner.list_stream(query)
+    }
+
+    /// Fetches the Consumers whose labels match the selector
+    pub async fn list_selected(&self, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixConsumer>>> {
+        self.inner.list_selected(selector).await
+    }
+
+    /// Fetches the Consumer with the specified username
+    pub async fn get(&self, username: &str) -> Result<TypedItem<ApisixConsumer>> {
+        self.inner.get(username).await
+    }
+
+    /// Creates or replaces the Consumer with the specified username
+    ///
+    /// The username of the request is set when missing, a different username in the request is rejected.
+    #[instrument(skip(self, req))]
+    pub async fn put(&self, username: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
+        let req = with_username(username, req)?;
+        let url = format!("{}{}", self.connector.cfg.admin_url, self.inner.path());
+        debug!("admin_api::put_consumer: {}", url);
+        put::<ConsumerRequest, TypedItem<ApisixConsumer>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str(), &req).await
+    }
+
+    /// Removes the Consumer with the specified username
+    pub async fn delete(&self, username: &str) -> Result<()> {
+        self.inner.delete(username).await
+    }
+
+    /// Removes the Consumer with the specified username, treating a missing Consumer as success
+    pub async fn delete_if_exists(&self, username: &str) -> Result<bool> {
+        self.inner.delete_if_exists(username).await
+    }
+
+    /// Fetches the Consumer with the specified username, creating it from `req` when it does not exist
+    #[instrument(skip(self, req))]
+    pub async fn get_or_create(&self, username: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
+        match self.get(username).await {
+            Err(e) if e.is_not_found() => {
+                info!("Consumer {} not found, creating", username);
+                self.put(username, req).await
+            },
+            res => res,
+        }
+    }
+
+    /// Credentials of the Consumer with the specified username (`/apisix/admin/consumers/{username}/credentials`)
+    pub fn credentials(&self, username: &str) -> AdminResource<'a, CredentialRequest, ApisixCredential> {
+        AdminResource::new(self.connector, path_consumer_credentials(username))
+    }
+
+    /// Starts a key rotation: adds a new key-auth Credential holding `new_key` to the Consumer
+    ///
+    /// The old Credential stays active until [ConsumerResource::finish_key_rotation] is called,
+    /// so both keys are accepted during the rotation window.
+    #[instrument(skip(self, new_key))]
+    pub async fn start_key_rotation(&self, username: &str, old_credential_id: &str, new_key: &str) -> Result<KeyRotation> {
+        // fail early when the credential to retire does not exist
+        self.credentials(username).get(old_credential_id).await?;
+        let req = CredentialBuilder::new()
+            .with_desc(format!("key rotation of {}", old_credential_id))
+            .with_key_auth(new_key)?
+            .build()?;
+        let new_credential_id = req.id.clone().unwrap_or_default();
+        self.credentials(username).put(new_credential_id.as_str(), &req).await?;
+        info!("Consumer {}: credential {} added, {} pending retirement", username, new_credential_id, old_credential_id);
+        Ok(KeyRotation {
+            username: username.to_string(),
+            old_credential_id: old_credential_id.to_string(),
+            new_credential_id,
+        })
+    }
+
+    /// Completes a key rotation once the clients confirmed the switch to the new key: removes the old Credential
+    #[instrument(skip(self))]
+    pub async fn finish_key_rotation(&self, rotation: &KeyRotation) -> Result<()> {
+        self.credentials(rotation.username.as_str()).delete_if_exists(rotation.old_credential_id.as_str()).await?;
+        Ok(())
+    }
+
+    /// Cancels a key rotation: removes the new Credential and keeps the old one
+    #[instrument(skip(self))]
+    pub async fn abort_key_rotation(&self, rotation: &KeyRotation) -> Result<()> {
+        self.credentials(rotation.username.as_str()).delete_if_exists(rotation.new_credential_id.as_str()).await?;
+        Ok(())
+    }
+
+    /// Renames a Consumer: copies the Consumer and its Credentials as stored by Apisix to `new_username`
+    /// and removes the old Consumer once the copy is stored
+    ///
+    /// The copy is made on the raw objects, plugins that are not modelled by this crate are kept.
+    /// Fails with [ApisixClientError::Conflict] when `new_username` already exists. When a step fails
+    /// after `new_username` was created, it is removed again and the error is returned.
+    #[instrument(skip(self))]
+    pub async fn rename(&self, username: &str, new_username: &str) -> Result<TypedItem<ApisixConsumer>> {
+        let mut consumer = self.connector.resource(ResourceKind::consumers).get(username).await?.value.unwrap_or_default();
+        match self.get(new_username).await {
+            Ok(_) => return Err(ApisixClientError::Conflict {
+                status: None,
+                message: format!("consumer {} already exists", new_username),
+                path: format!("{}/{}", self.path(), new_username),
+                error: None,
+            }),
+            Err(e) if e.is_not_found() => {},
+            Err(e) => return Err(e),
+        }
+        let credentials = match self.raw_credentials(username).list().await {
+            Ok(page) => page.list,
+            Err(e) if e.is_not_found() => vec![],
+            Err(e) => return Err(e),
+        };
+        if let Some(fields) = consumer.as_object_mut() {
+            fields.insert("username".to_string(), Value::String(new_username.to_string()));
+        }
+        let url = format!("{}{}", self.connector.cfg.admin_url, self.inner.path());
+        let renamed = put::<Value, TypedItem<ApisixConsumer>>(&self.connector.http, url.as_str(),
+            self.connector.cfg.admin_apikey.as_str(), &without_server_fields(&consumer)).await?;
+        let copied = async {
+            for credential in credentials.into_iter().filter_map(|item| item.value) {
+                let id = credential.get("id").and_then(id_string).unwrap_or_default();
+                debug!("Copying credential {} of consumer {} to {}", id, username, new_username);
+                self.raw_credentials(new_username).put(id.as_str(), &without_server_fields(&credential)).await?;
+            }
+            self.delete(username).await
+        };
+        if let Err(e) = copied.await {
+            warn!("Renaming consumer {} to {} failed, removing {}: {}", username, new_username, new_username, e);
+            if let Err(cleanup) = self.delete_if_exists(new_username).await {
+                warn!("Unable to remove consumer {}: {}", new_username, cleanup);
+            }
+            return Err(e);
+        }
+        Ok(renamed)
+    }
+
+    /// Credentials of the Consumer handled as [serde_json::Value]
+    fn raw_credentials(&self, username: &str) -> AdminResource<'a, Value, Value> {
+        AdminResource::new(self.connector, path_consumer_credentials(username))
+    }
+}
+
+/// Pending key rotation, see [ConsumerResource::start_key_rotation]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRotation {
+    pub username: String,
+    pub old_credential_id: String,
+    pub new_credential_id: String,
+}
+
+/// Aligns the username of the request with the username passed as argument
+fn with_username(username: &str, req: &ConsumerRequest) -> Result<ConsumerRequest> {
+    match req.username.as_deref() {
+        Some(u) if u != username => Err(ApisixClientError::InvalidRequest(
+            format!("consumer username mismatch: argument {} vs body {}", username, u))),
+        _ => Ok(ConsumerRequest {
+            username: Some(username.to_string()),
+            ..req.clone()
+        }),
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::client::test_server::TestServer;
+    use crate::models::ConsumerBuilder;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_consumer_username_check() {
+        let req = ConsumerBuilder::new().with_desc("test".to_string()).build().unwrap();
+        assert_eq!(with_username("alice", &req).unwrap().username.unwrap(), "alice");
+
+        let req = ConsumerBuilder::new().with_username("alice".to_string()).build().unwrap();
+        assert!(with_username("alice", &req).is_ok());
+        assert!(matches!(with_username("bob", &req), Err(ApisixClientError::InvalidRequest(_))));
+    }
+
+    const JACK: &str = "/apisix/admin/consumers/jack";
+
+    fn jack() -> Value {
+        json!({"key": "/apisix/consumers/jack", "modifiedIndex": 3, "value": {
+            "username": "jack", "create_time": 1, "update_time": 2,
+            "plugins": {"wolf-rbac": {"server": "http://wolf:12180"}}
+        }})
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_rename_keeps_raw_consumer() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", JACK, 200, jack())
+            .on("GET", "/apisix/admin/consumers/jill", 404, json!({"message": "Key not found"}))
+            .on("GET", "/apisix/admin/consumers/jack/credentials", 404, json!({"message": "Key not found"}))
+            .on("PUT", "/apisix/admin/consumers", 200, json!({"key": "/apisix/consumers/jill", "value": {"username": "jill"}}))
+            .on("DELETE", JACK, 200, json!({"deleted": "1"}));
+        let connector = server.connector();
+
+        let renamed = connector.consumers().rename("jack", "jill").await.unwrap();
+        assert_eq!(renamed.value.unwrap().username.as_deref(), Some("jill"));
+        let put = server.requests().into_iter().find(|r| r.method == "PUT").unwrap();
+        assert_eq!(put.body, Some(json!({"username": "jill", "plugins": {"wolf-rbac": {"server": "http://wolf:12180"}}})));
+        assert_eq!(server.calls().last().unwrap(), &format!("DELETE {}", JACK));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_rename_removes_copy_on_error() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", JACK, 200, jack())
+            .on("GET", "/apisix/admin/consumers/jill", 404, json!({"message": "Key not found"}))
+            .on("GET", "/apisix/admin/consumers/jack/credentials", 200, json!({"total": 1, "list": [
+                {"key": "/apisix/consumers/jack/credentials/c1", "value": {"id": "c1", "plugins": {"key-auth": {"key": "k1"}}}}
+            ]}))
+            .on("PUT", "/apisix/admin/consumers", 200, json!({"key": "/apisix/consumers/jill", "value": {"username": "jill"}}))
+            .on("PUT", "/apisix/admin/consumers/jill/credentials/c1", 500, json!({"error_msg": "etcd unavailable"}))
+            .on("DELETE", "/apisix/admin/consumers/jill", 200, json!({"deleted": "1"}));
+        let connector = server.connector();
+
+        let error = connector.consumers().rename("jack", "jill").await.unwrap_err();
+        assert_eq!(error.status(), Some(500));
+        let calls = server.calls();
+        assert_eq!(calls.last().unwrap(), "DELETE /apisix/admin/consumers/jill");
+        assert!(!calls.contains(&format!("DELETE {}", JACK)));
+    }
+}
+// endregion: tests
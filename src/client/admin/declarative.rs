@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+use futures::{stream, StreamExt, TryStreamExt};
+use serde_json::Value;
+use tracing::{info, instrument, warn};
+use crate::client::admin::{value_labels, AdminConnector, BulkItemResult, BulkReport};
+use crate::error::ApisixClientError;
+use crate::models::{DesiredState, DriftReport, LiveState, Plan, PlanAction, PlanOp, ResourceKind, Snapshot, OWNER_LABEL};
+use crate::Result;
+
+/// Live kinds fetched in parallel when planning
+const PLAN_CONCURRENCY: usize = 4;
+
+/// Options of [AdminConnector::apply]
+#[derive(Debug, Clone)]
+pub struct ApplyOptions {
+    /// Reports what would be done without changing anything
+    pub dry_run: bool,
+    /// Deletes the live objects missing from the desired state, only when labelled [OWNER_LABEL] with this owner
+    pub prune: Option<String>,
+    /// Stops at the first failed step, otherwise the remaining steps are still attempted
+    pub stop_on_error: bool,
+    /// Restores the objects read again before the first step when stopping on error
+    pub rollback: bool,
+}
+
+impl Default for ApplyOptions {
+    fn default() -> Self {
+        ApplyOptions {
+            dry_run: false,
+            prune: None,
+            stop_on_error: true,
+            rollback: true,
+        }
+    }
+}
+
+impl ApplyOptions {
+    pub fn new() -> Self {
+        ApplyOptions::default()
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn with_prune(mut self, owner: impl Into<String>) -> Self {
+        self.prune = Some(owner.into());
+        self
+    }
+
+    pub fn with_stop_on_error(mut self, stop_on_error: bool) -> Self {
+        self.stop_on_error = stop_on_error;
+        self
+    }
+
+    pub fn with_rollback(mut self, rollback: bool) -> Self {
+        self.rollback = rollback;
+        self
+    }
+
+    /// True when the delete action may be executed
+    fn prunes(&self, action: &PlanAction) -> bool {
+        let Some(owner) = &self.prune else { return false };
+        action.live.as_ref()
+            .and_then(value_labels)
+            .is_some_and(|labels| labels.get(OWNER_LABEL) == Some(owner))
+    }
+}
+
+/// Outcome of [AdminConnector::apply]
+#[derive(Debug, Default)]
+pub struct ApplyReport {
+    pub dry_run: bool,
+    /// Steps executed (or that would be executed on a dry run), in order
+    pub applied: BulkReport,
+    /// Deletes not allowed by the prune option and steps left out after stopping on error
+    pub skipped: Vec<(ResourceKind, String)>,
+    /// Steps undone after a failure, in order
+    pub rolled_back: Option<BulkReport>,
+}
+
+impl ApplyReport {
+    /// True when every executed step succeeded
+    pub fn is_success(&self) -> bool {
+        self.applied.is_success()
+    }
+}
+
+impl AdminConnector {
+    /// Raw live objects of the kinds, keyed by kind then by id, see [ResourceKind::id_from_key]
+    #[instrument(skip(self))]
+    pub async fn live_state(&self, kinds: &[ResourceKind]) -> Result<LiveState> {
+        stream::iter(kinds)
+            .map(|kind| async move {
+                let objects = self.list_entries(*kind).await?.into_iter().map(|(id, _, value)| (id, value)).collect();
+                Ok((*kind, objects))
+            })
+            .buffered(PLAN_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Computes the actions needed to converge the gateway to the desired state, nothing is changed
+    #[instrument(skip_all)]
+    pub async fn plan(&self, desired: &DesiredState) -> Result<Plan> {
+        let live = self.live_state(&desired.managed_kinds()).await?;
+        desired.plan(&live)
+    }
+
+    /// Raw live objects of every kind of [ResourceKind::ALL], Credentials, Secrets and Plugin metadata included
+    #[instrument(skip(self))]
+    pub async fn snapshot(&self) -> Result<Snapshot> {
+        let live = self.live_state(&ResourceKind::ALL).await?;
+        Ok(Snapshot::new(live))
+    }
+
+    /// Compares the live gateway with a snapshot saved earlier, see [Snapshot::drift]
+    #[instrument(skip_all)]
+    pub async fn drift_from_snapshot(&self, snapshot: &Snapshot) -> Result<DriftReport> {
+        let live = self.live_state(&snapshot.kinds()).await?;
+        Ok(snapshot.drift(&live))
+    }
+
+    /// Compares the live gateway with the desired state, see [DesiredState::drift]
+    #[instrument(skip_all)]
+    pub async fn drift_from_desired(&self, desired: &DesiredState) -> Result<DriftReport> {
+        let live = self.live_state(&desired.managed_kinds()).await?;
+        desired.drift(&live)
+    }
+
+    /// Executes the create, update and delete actions of the plan, in the plan order
+    ///
+    /// With `stop_on_error` and `rollback` set, the objects the run may change are read again before the
+    /// first step, the plan may be stale. When a step fails the steps already executed are undone in reverse
+    /// order back to that state: objects absent then are removed, the others are put back.
+    /// Fails without changing anything when those objects cannot be read.
+    #[instrument(skip_all, fields(dry_run = opts.dry_run))]
+    pub async fn apply(&self, plan: &Plan, opts: &ApplyOptions) -> Result<ApplyReport> {
+        let before = if !opts.dry_run && opts.stop_on_error && opts.rollback {
+            self.read_affected(plan, opts).await?
+        } else {
+            BTreeMap::new()
+        };
+        let mut report = ApplyReport { dry_run: opts.dry_run, ..ApplyReport::default() };
+        let mut done: Vec<&PlanAction> = vec![];
+        let mut actions = plan.changes();
+        for action in actions.by_ref() {
+            if action.op == PlanOp::delete && !opts.prunes(action) {
+                report.skipped.push((action.kind, action.id.clone()));
+                continue;
+            }
+            let error = if opts.dry_run { None } else { self.apply_action(action).await.err() };
+            let failed = error.is_some();
+            if let Some(e) = &error {
+                warn!("apply {} {}/{} failed: {:?}", action.op, action.kind, action.id, e);
+            }
+            report.applied.items.push(BulkItemResult { kind: action.kind, id: action.id.clone(), error });
+            if failed && opts.stop_on_error {
+                break;
+            }
+            done.push(action);
+        }
+        report.skipped.extend(actions.map(|a| (a.kind, a.id.clone())));
+        if !report.is_success() && opts.stop_on_error && opts.rollback {
+            let mut rolled_back = BulkReport::default();
+            for action in done.into_iter().rev() {
+                let error = self.undo_action(action, before.get(&(action.kind, action.id.as_str()))).await.err();
+                rolled_back.items.push(BulkItemResult { kind: action.kind, id: action.id.clone(), error });
+            }
+            warn!("apply rolled back {} steps, {} failed", rolled_back.items.len(), rolled_back.failed().count());
+            report.rolled_back = Some(rolled_back);
+        }
+        info!("apply: {} steps, {} failed, {} skipped", report.applied.items.len(), report.applied.failed().count(), report.skipped.len());
+        Ok(report)
+    }
+
+    /// Current value of the objects the plan may change, the absent ones are left out
+    async fn read_affected<'a>(&self, plan: &'a Plan, opts: &ApplyOptions) -> Result<BTreeMap<(ResourceKind, &'a str), Value>> {
+        let objects: Vec<_> = stream::iter(plan.changes().filter(|a| a.op != PlanOp::delete || opts.prunes(a)))
+            .map(|action| async move {
+                match self.resource(action.kind).get(&action.id).await {
+                    Ok(item) => Ok(item.value.map(|value| ((action.kind, action.id.as_str()), value))),
+                    Err(e) if e.is_not_found() => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .buffered(PLAN_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(objects.into_iter().flatten().collect())
+    }
+
+    async fn apply_action(&self, action: &PlanAction) -> Result<()> {
+        match (action.op, &action.desired) {
+            (PlanOp::create | PlanOp::update, Some(desired)) => self.apply_object(desired).await,
+            (PlanOp::delete, _) => self.resource(action.kind).delete_if_exists(&action.id).await.map(|_| ()),
+            _ => Err(ApisixClientError::InvalidRequest(format!("no desired object for {} {}/{}", action.op, action.kind, action.id))),
+        }
+    }
+
+    async fn undo_action(&self, action: &PlanAction, before: Option<&Value>) -> Result<()> {
+        match before {
+            Some(value) => self.put_value(action.kind, &action.id, value).await,
+            None => self.resource(action.kind).delete_if_exists(&action.id).await.map(|_| ()),
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::client::test_server::TestServer;
+    use crate::models::{index_live_state, AdminObject};
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_apply_dry_run() {
+        let desired = DesiredState::from_yaml_str("upstreams:\n  - id: u1\n    nodes: { \"127.0.0.1:80\": 1 }\n").unwrap();
+        let live = index_live_state([(ResourceKind::upstreams, vec![
+            json!({"id": "owned", "labels": {OWNER_LABEL: "gitops"}}),
+            json!({"id": "manual"}),
+        ])]);
+        let plan = desired.plan(&live).unwrap();
+        let opts = ApplyOptions::new().with_dry_run(true).with_prune("gitops");
+        let report = AdminConnector::default().apply(&plan, &opts).await.unwrap();
+        assert!(report.is_success());
+        let applied: Vec<&str> = report.applied.items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(applied, vec!["u1", "owned"]);
+        assert_eq!(report.skipped, vec![(ResourceKind::upstreams, "manual".to_string())]);
+        assert!(report.rolled_back.is_none());
+    }
+
+    fn action(op: PlanOp, id: &str, live: Option<Value>) -> PlanAction {
+        let desired = (op != PlanOp::delete).then(|| {
+            AdminObject::from_value(ResourceKind::upstreams, json!({"id": id, "nodes": {"127.0.0.1:80": 1}})).unwrap()
+        });
+        PlanAction { kind: ResourceKind::upstreams, id: id.to_string(), op, diffs: vec![], desired, live }
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_apply_rollback_order() {
+        let server = TestServer::start().await;
+        let item = |id: &str, desc: &str| json!({"key": format!("/apisix/upstreams/{}", id), "value": {"id": id, "desc": desc}});
+        server
+            .on("GET", "/apisix/admin/upstreams/u2", 200, item("u2", "fresh"))
+            .on("GET", "/apisix/admin/upstreams/u3", 200, item("u3", "owned"))
+            .on("GET", "/apisix/admin/upstreams/u4", 200, item("u4", "fresh"))
+            .on("PUT", "/apisix/admin/upstreams/u1", 201, item("u1", ""))
+            .on("PUT", "/apisix/admin/upstreams/u2", 200, item("u2", ""))
+            .on("PUT", "/apisix/admin/upstreams/u3", 200, item("u3", ""))
+            .on("PUT", "/apisix/admin/upstreams/u4", 500, json!({"error_msg": "boom"}))
+            .on("DELETE", "/apisix/admin/upstreams/u1", 200, json!({}))
+            .on("DELETE", "/apisix/admin/upstreams/u3", 200, json!({}));
+        let owned = json!({"id": "u3", "labels": {OWNER_LABEL: "gitops"}});
+        let plan = Plan { actions: vec![
+            action(PlanOp::create, "u1", None),
+            action(PlanOp::update, "u2", Some(json!({"id": "u2", "desc": "stale"}))),
+            action(PlanOp::delete, "u3", Some(owned)),
+            action(PlanOp::update, "u4", Some(json!({"id": "u4"}))),
+            action(PlanOp::create, "u5", None),
+        ]};
+
+        let report = server.connector().apply(&plan, &ApplyOptions::new().with_prune("gitops")).await.unwrap();
+        assert!(!report.is_success());
+        assert_eq!(report.skipped, vec![(ResourceKind::upstreams, "u5".to_string())]);
+        let rolled_back: Vec<&str> = report.rolled_back.as_ref().unwrap().items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(rolled_back, vec!["u3", "u2", "u1"]);
+        assert!(report.rolled_back.unwrap().is_success());
+
+        let changes: Vec<String> = server.calls().into_iter().filter(|c| !c.starts_with("GET")).collect();
+        assert_eq!(changes, vec![
+            "PUT /apisix/admin/upstreams/u1",
+            "PUT /apisix/admin/upstreams/u2",
+            "DELETE /apisix/admin/upstreams/u3",
+            "PUT /apisix/admin/upstreams/u4",
+            "PUT /apisix/admin/upstreams/u3",
+            "PUT /apisix/admin/upstreams/u2",
+            "DELETE /apisix/admin/upstreams/u1",
+        ]);
+        let restored = server.requests().into_iter().rfind(|r| r.method == "PUT" && r.path.ends_with("/u2")).unwrap();
+        assert_eq!(restored.body.unwrap()["desc"], "fresh");
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_apply_unreadable_before_run() {
+        let server = TestServer::start().await;
+        server.on("GET", "/apisix/admin/upstreams/u1", 500, json!({"error_msg": "boom"}));
+        let plan = Plan { actions: vec![action(PlanOp::create, "u1", None)] };
+        assert!(server.connector().apply(&plan, &ApplyOptions::new()).await.is_err());
+        assert_eq!(server.calls(), vec!["GET /apisix/admin/upstreams/u1"]);
+    }
+}
+// endregion: tests
@@ -1,6 +1,18 @@
 mod client;
+mod resource;
+mod consumers;
+mod plugin_metadata;
+mod bulk;
+mod declarative;
+mod backup;
 pub use client::*;
+pub use resource::*;
+pub use consumers::*;
+pub use plugin_metadata::*;
+pub use bulk::*;
+pub use declarative::*;
 //pub (crate) mod routes;
+use crate::models::{ResourceKind, SecretManager};
 
 // region: mod constants
 const ADMIN_PATH: &str = "/apisix/admin";
@@ -9,15 +21,23 @@ const ADMIN_PATH: &str = "/apisix/admin";
 // region: Path definitions
 fn path_check_version() -> String { ADMIN_PATH.to_string() }
 fn path_upstreams() -> String { format!("{}/upstreams", ADMIN_PATH) }
-fn path_upstream_with_id(id: &str) -> String { format!("{}/upstreams/{}", ADMIN_PATH, id) }
 fn path_services() -> String { format!("{}/services", ADMIN_PATH) }
-fn path_service_with_id(id: &str) -> String { format!("{}/services/{}", ADMIN_PATH, id) }
 fn path_routes() -> String { format!("{}/routes", ADMIN_PATH) }
-fn path_route_with_id(id: &str) -> String { format!("{}/routes/{}", ADMIN_PATH, id) }
 fn path_consumer_groups() -> String { format!("{}/consumer_groups", ADMIN_PATH) }
-fn path_consumer_group_with_id(id: &str) -> String { format!("{}/consumer_groups/{}", ADMIN_PATH, id) }
 fn path_consumer() -> String { format!("{}/consumers", ADMIN_PATH) }
-fn path_consumer_with_id(id: &str) -> String { format!("{}/consumers/{}", ADMIN_PATH, id) }
+fn path_ssls() -> String { format!("{}/ssls", ADMIN_PATH) }
+fn path_global_rules() -> String { format!("{}/global_rules", ADMIN_PATH) }
+fn path_plugin_configs() -> String { format!("{}/plugin_configs", ADMIN_PATH) }
+fn path_stream_routes() -> String { format!("{}/stream_routes", ADMIN_PATH) }
+fn path_secrets(manager: &SecretManager) -> String { format!("{}/secrets/{}", ADMIN_PATH, manager) }
+fn path_plugin_metadata() -> String { format!("{}/plugin_metadata", ADMIN_PATH) }
+fn path_protos() -> String { format!("{}/protos", ADMIN_PATH) }
+/// Collection of the kind, Credentials are addressed under the Consumers: `{username}/credentials/{id}`
+fn path_kind(kind: ResourceKind) -> String {
+    match kind {
+        ResourceKind::credentials => path_consumer(),
+        _ => format!("{}/{}", ADMIN_PATH, kind),
+    }
+}
+fn path_consumer_credentials(username: &str) -> String { format!("{}/consumers/{}/credentials", ADMIN_PATH, username) }
 // endregion: Path definitions
-
-
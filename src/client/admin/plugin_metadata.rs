@@ -0,0 +1,59 @@
+use serde_json::Value;
+use crate::client::admin::{path_plugin_metadata, AdminConnector, AdminResource};
+use crate::models::common::TypedItem;
+use crate::models::{ApisixLoggerMetadata, LoggerMetadataRequest, LoggerPlugin};
+use crate::Result;
+
+/// Plugin metadata resource (`/apisix/admin/plugin_metadata`)
+///
+/// Plugin metadata is keyed by plugin name. The logger plugins are typed,
+/// the metadata of any other plugin is handled as a [serde_json::Value].
+#[derive(Debug)]
+pub struct PluginMetadataResource<'a> {
+    loggers: AdminResource<'a, LoggerMetadataRequest, ApisixLoggerMetadata>,
+    raw: AdminResource<'a, Value, Value>,
+}
+
+impl<'a> PluginMetadataResource<'a> {
+    pub(crate) fn new(connector: &'a AdminConnector) -> Self {
+        PluginMetadataResource {
+            loggers: AdminResource::new(connector, path_plugin_metadata()),
+            raw: AdminResource::new(connector, path_plugin_metadata()),
+        }
+    }
+
+    /// Path of the collection, relative to the admin url
+    pub fn path(&self) -> &str {
+        self.raw.path()
+    }
+
+    /// Fetches the metadata of a logger plugin
+    pub async fn get_logger(&self, plugin: &LoggerPlugin) -> Result<TypedItem<ApisixLoggerMetadata>> {
+        self.loggers.get(plugin.to_string().as_str()).await
+    }
+
+    /// Sets the metadata of a logger plugin
+    pub async fn put_logger(&self, plugin: &LoggerPlugin, req: &LoggerMetadataRequest) -> Result<TypedItem<ApisixLoggerMetadata>> {
+        self.loggers.put(plugin.to_string().as_str(), req).await
+    }
+
+    /// Fetches the metadata of the specified plugin
+    pub async fn get(&self, plugin_name: &str) -> Result<TypedItem<Value>> {
+        self.raw.get(plugin_name).await
+    }
+
+    /// Sets the metadata of the specified plugin
+    pub async fn put(&self, plugin_name: &str, metadata: &Value) -> Result<TypedItem<Value>> {
+        self.raw.put(plugin_name, metadata).await
+    }
+
+    /// Removes the metadata of the specified plugin
+    pub async fn delete(&self, plugin_name: &str) -> Result<()> {
+        self.raw.delete(plugin_name).await
+    }
+
+    /// Removes the metadata of the specified plugin, treating missing metadata as success
+    pub async fn delete_if_exists(&self, plugin_name: &str) -> Result<bool> {
+        self.raw.delete_if_exists(plugin_name).await
+    }
+}
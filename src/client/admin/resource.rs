@@ -0,0 +1,354 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use futures::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{debug, instrument, warn};
+use crate::client::admin::bulk::without_server_fields;
+use crate::client::admin::AdminConnector;
+use crate::client::reqwest_generic::{delete, get, patch, put};
+use crate::models::common::{ListResponse, TypedItem};
+use crate::models::{LabelSelector, Labelled, ListQuery, ListQueryBuilder, DEFAULT_PAGE_SIZE};
+use crate::error::ApisixClientError;
+use crate::Result;
+
+/// Typed handle on a collection of the Admin API, e.g. `/apisix/admin/routes`
+///
+/// `Req` is the model sent on create/update, `Resp` the model returned by Apisix
+#[derive(Debug)]
+pub struct AdminResource<'a, Req, Resp> {
+    connector: &'a AdminConnector,
+    path: String,
+    listable: bool,
+    _models: PhantomData<(Req, Resp)>,
+}
+
+impl<'a, Req, Resp> AdminResource<'a, Req, Resp>
+where
+    Req: Serialize + Debug,
+    Resp: DeserializeOwned,
+{
+    pub(crate) fn new(connector: &'a AdminConnector, path: String) -> Self {
+        AdminResource {
+            connector,
+            path,
+            listable: true,
+            _models: PhantomData,
+        }
+    }
+
+    /// Resource whose objects are only reachable by id, listing fails with [ApisixClientError::InvalidRequest]
+    pub(crate) fn unlisted(mut self) -> Self {
+        self.listable = false;
+        self
+    }
+
+    fn check_listable(&self) -> Result<()> {
+        if self.listable {
+            Ok(())
+        } else {
+            Err(ApisixClientError::InvalidRequest(format!("{} cannot be listed as a whole", self.path)))
+        }
+    }
+
+    /// Path of the collection, relative to the admin url
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    fn url(&self) -> String {
+        format!("{}{}", self.connector.cfg.admin_url, self.path)
+    }
+
+    fn url_with_id(&self, id: &str) -> String {
+        format!("{}{}/{}", self.connector.cfg.admin_url, self.path, id)
+    }
+
+    fn url_with_sub_path(&self, id: &str, sub_path: &str) -> String {
+        format!("{}/{}", self.url_with_id(id), sub_path.trim_matches('/'))
+    }
+
+    /// Fetches all the objects of the collection
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn list(&self) -> Result<ListResponse<TypedItem<Resp>>> {
+        self.check_listable()?;
+        let url = self.url();
+        debug!("admin_api::list: {}", url);
+        get::<ListResponse<TypedItem<Resp>>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str()).await
+    }
+
+    /// Fetches the objects of the collection matching the query, see [ListQuery]
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn list_with(&self, query: &ListQuery) -> Result<ListResponse<TypedItem<Resp>>> {
+        self.check_listable()?;
+        query.check()?;
+        let url = reqwest::Url::parse_with_params(self.url().as_str(), query.params())
+            .map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))?;
+        debug!("admin_api::list_with: {}", url);
+        get::<ListResponse<TypedItem<Resp>>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str()).await
+    }
+
+    /// Walks all the pages of the collection matching the query, one page is fetched at a time
+    ///
+    /// Starts at the page of the query (default 1), using its page size (default [DEFAULT_PAGE_SIZE]).
+    /// Stops after a page shorter than the page size or once `total` objects were fetched.
+    pub fn list_stream(&self, query: &ListQuery) -> impl Stream<Item = Result<TypedItem<Resp>>> + '_ {
+        let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        let first = query.with_page(query.page.unwrap_or(1), page_size);
+        stream::try_unfold(Some(first), move |next| async move {
+            let Some(query) = next else {
+                return Ok(None);
+            };
+            let page = self.list_with(&query).await?;
+            let page_number = query.page.unwrap_or(1);
+            let fetched = (page_number - 1) * page_size + page.list.len() as i64;
+            let next = if page.list.len() as i64 >= page_size && fetched < page.total as i64 {
+                Some(query.with_page(page_number + 1, page_size))
+            } else {
+                None
+            };
+            Ok(Some((stream::iter(page.list.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+    }
+
+    /// Fetches all the objects whose labels match the selector, e.g. `env=prod,team=payments`
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn list_selected(&self, selector: &LabelSelector) -> Result<Vec<TypedItem<Resp>>>
+    where
+        Resp: Labelled,
+    {
+        let mut query = ListQueryBuilder::new();
+        if let Some(label) = selector.server_filter() {
+            query = query.with_label(label);
+        }
+        self.list_stream(&query.build()?)
+            .try_filter(|item| futures::future::ready(selector.matches(item.value.as_ref().and_then(|v| v.labels()))))
+            .try_collect()
+            .await
+    }
+
+    /// Fetches the object with the specified id
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn get(&self, id: &str) -> Result<TypedItem<Resp>> {
+        let url = self.url_with_id(id);
+        debug!("admin_api::get: {}", url);
+        get::<TypedItem<Resp>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str()).await
+    }
+
+    /// Creates or replaces the object with the specified id
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn put(&self, id: &str, req: &Req) -> Result<TypedItem<Resp>> {
+        let url = self.url_with_id(id);
+        debug!("admin_api::put: {}", url);
+        put::<Req, TypedItem<Resp>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str(), req).await
+    }
+
+    /// Partially updates the object with the specified id
+    ///
+    /// `doc` is merged into the stored object: fields that are absent are kept, fields set to `null` are removed.
+    /// Fields that are not modelled by this crate are preserved.
+    #[instrument(skip(self, doc), fields(path = %self.path))]
+    pub async fn patch<P>(&self, id: &str, doc: &P) -> Result<TypedItem<Resp>>
+    where
+        P: Serialize + Debug,
+    {
+        let url = self.url_with_id(id);
+        debug!("admin_api::patch: {}", url);
+        patch::<P, TypedItem<Resp>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str(), doc).await
+    }
+
+    /// Replaces a single attribute of the object with the specified id, e.g. `plugins` or `upstream/nodes`
+    ///
+    /// Unlike [AdminResource::patch], the value at `sub_path` is replaced as a whole and not merged.
+    #[instrument(skip(self, value), fields(path = %self.path))]
+    pub async fn patch_sub_path<P>(&self, id: &str, sub_path: &str, value: &P) -> Result<TypedItem<Resp>>
+    where
+        P: Serialize + Debug,
+    {
+        let url = self.url_with_sub_path(id, sub_path);
+        debug!("admin_api::patch_sub_path: {}", url);
+        patch::<P, TypedItem<Resp>>(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str(), value).await
+    }
+
+    /// Read-modify-write of the object with the specified id, guarded by its `modifiedIndex`
+    ///
+    /// `f` edits the object as stored by Apisix, fields that are not modelled by this crate are kept.
+    /// The object is read again before the write, when its `modifiedIndex` changed in the meantime
+    /// `f` is applied again on that fresh read, up to `retries` times before failing with [ApisixClientError::Conflict].
+    /// Apisix has no conditional write, a change landing between the final check and the write is not detected.
+    #[instrument(skip(self, f), fields(path = %self.path))]
+    pub async fn update_with<F>(&self, id: &str, retries: u32, mut f: F) -> Result<TypedItem<Resp>>
+    where
+        F: FnMut(&mut Value) -> Result<()>,
+    {
+        let url = self.url_with_id(id);
+        let apikey = self.connector.cfg.admin_apikey.as_str();
+        let mut attempt = 0;
+        let mut current = get::<TypedItem<Value>>(&self.connector.http, url.as_str(), apikey).await?;
+        loop {
+            let mut value = current.value.clone().ok_or(ApisixClientError::InvalidRequest(format!("{}/{} has no value", self.path, id)))?;
+            f(&mut value)?;
+            let latest = get::<TypedItem<Value>>(&self.connector.http, url.as_str(), apikey).await?;
+            let actual = latest.modified_index;
+            if actual == current.modified_index {
+                debug!("admin_api::update_with: {}", url);
+                return put::<Value, TypedItem<Resp>>(&self.connector.http, url.as_str(), apikey, &without_server_fields(&value)).await;
+            }
+            if attempt >= retries {
+                return Err(ApisixClientError::Conflict {
+                    status: None,
+                    message: format!("modifiedIndex {:?} changed to {:?}", current.modified_index, actual),
+                    path: format!("{}/{}", self.path, id),
+                    error: None,
+                });
+            }
+            attempt += 1;
+            warn!("{}/{} modified concurrently ({:?} -> {:?}), retry {}/{}", self.path, id, current.modified_index, actual, attempt, retries);
+            current = latest;
+        }
+    }
+
+    /// Removes the object with the specified id
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        let url = self.url_with_id(id);
+        debug!("admin_api::delete: {}", url);
+        delete(&self.connector.http, url.as_str(), self.connector.cfg.admin_apikey.as_str()).await
+    }
+
+    /// Removes the object with the specified id, treating a missing object as success
+    /// Returns `false` when the object did not exist
+    #[instrument(skip(self), fields(path = %self.path))]
+    pub async fn delete_if_exists(&self, id: &str) -> Result<bool> {
+        match self.delete(id).await {
+            Ok(()) => Ok(true),
+            Err(e) if e.is_not_found() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use serde_json::json;
+    use crate::client::test_server::TestServer;
+    use crate::models::{ApisixRoute, ResourceKind, RouteRequest};
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_sub_path_url() {
+        let connector = AdminConnector::default();
+        let routes: AdminResource<RouteRequest, ApisixRoute> = AdminResource::new(&connector, "/apisix/admin/routes".to_string());
+        assert_eq!(routes.url_with_sub_path("r1", "/plugins"), format!("{}/apisix/admin/routes/r1/plugins", connector.cfg.admin_url));
+        assert_eq!(routes.url_with_sub_path("r1", "upstream/nodes"), format!("{}/apisix/admin/routes/r1/upstream/nodes", connector.cfg.admin_url));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_error_status_mapping() {
+        let server = TestServer::start().await;
+        server
+            .on("PUT", "/apisix/admin/routes/r1", 400, json!({"error_msg": "invalid configuration: property \"uri\" is required"}))
+            .on("DELETE", "/apisix/admin/routes/r1", 200, json!({"deleted": "1"}))
+            .on("DELETE", "/apisix/admin/routes/r2", 404, json!({"message": "Key not found"}));
+        let connector = server.connector();
+        let routes = connector.routes();
+
+        let error = routes.put("r1", &RouteRequest::default()).await.unwrap_err();
+        assert!(matches!(&error, ApisixClientError::SchemaViolation { message, path, .. }
+            if message.contains("\"uri\" is required") && path.ends_with("/apisix/admin/routes/r1")), "{:?}", error);
+
+        assert!(routes.delete_if_exists("r1").await.unwrap());
+        assert!(!routes.delete_if_exists("r2").await.unwrap());
+        assert!(routes.delete("r2").await.unwrap_err().is_not_found());
+    }
+
+    /// Page of `count` upstreams out of `total`
+    fn page(total: usize, count: usize) -> Value {
+        let list: Vec<Value> = (0..count).map(|i| json!({"value": {"id": format!("u{}", i)}})).collect();
+        json!({"total": total, "list": list})
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_list_stream_pages() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/upstreams?page=1&page_size=10", 200, page(25, 10))
+            .on("GET", "/apisix/admin/upstreams?page=2&page_size=10", 200, page(25, 10))
+            .on("GET", "/apisix/admin/upstreams?page=3&page_size=10", 200, page(25, 5))
+            .on("GET", "/apisix/admin/services?page=1&page_size=10", 200, page(20, 10))
+            .on("GET", "/apisix/admin/services?page=2&page_size=10", 200, page(20, 10));
+        let connector = server.connector();
+        let query = ListQueryBuilder::new().with_page_size(10).build().unwrap();
+
+        // the last page is shorter than the page size
+        let upstreams: Vec<_> = connector.resource(ResourceKind::upstreams).list_stream(&query).try_collect().await.unwrap();
+        assert_eq!(upstreams.len(), 25);
+        // `total` is reached on a full page
+        let services: Vec<_> = connector.resource(ResourceKind::services).list_stream(&query).try_collect().await.unwrap();
+        assert_eq!(services.len(), 20);
+        assert_eq!(server.calls().len(), 5);
+
+        let invalid = ListQuery { page_size: Some(1000), ..query };
+        let error = connector.resource(ResourceKind::routes).list_stream(&invalid).try_collect::<Vec<_>>().await.unwrap_err();
+        assert!(matches!(error, ApisixClientError::InvalidRequest(_)));
+        assert_eq!(server.calls().len(), 5);
+    }
+
+    fn route(modified_index: i64, desc: &str) -> Value {
+        json!({"key": "/apisix/routes/r1", "modifiedIndex": modified_index, "value": {
+            "id": "r1", "uri": "/a", "desc": desc, "create_time": 1, "update_time": modified_index,
+            "plugins": {"ip-restriction": {"whitelist": ["10.0.0.0/8"]}}
+        }})
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_update_with_retries() {
+        let server = TestServer::start().await;
+        server
+            .on("GET", "/apisix/admin/routes/r1", 200, route(1, "v1"))
+            .on("GET", "/apisix/admin/routes/r1", 200, route(2, "v2"))
+            .on("PUT", "/apisix/admin/routes/r1", 200, route(3, "v2 updated"));
+        let connector = server.connector();
+
+        let mut seen = vec![];
+        let updated = connector.routes().update_with("r1", 1, |route| {
+            let desc = route["desc"].as_str().unwrap_or_default().to_string();
+            seen.push(desc.clone());
+            route["desc"] = json!(format!("{} updated", desc));
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(seen, vec!["v1", "v2"]);
+        assert_eq!(updated.modified_index, Some(3));
+        assert_eq!(server.calls().iter().filter(|c| c.starts_with("GET")).count(), 3);
+        let put = server.requests().into_iter().find(|r| r.method == "PUT").unwrap();
+        assert_eq!(put.body, Some(json!({
+            "id": "r1", "uri": "/a", "desc": "v2 updated",
+            "plugins": {"ip-restriction": {"whitelist": ["10.0.0.0/8"]}}
+        })));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_update_with_conflict() {
+        let server = TestServer::start().await;
+        for modified_index in 1..=4 {
+            server.on("GET", "/apisix/admin/routes/r1", 200, route(modified_index, "v"));
+        }
+        let connector = server.connector();
+
+        let error = connector.routes().update_with("r1", 1, |_| Ok(())).await.unwrap_err();
+        assert!(error.is_conflict(), "{:?}", error);
+        assert_eq!(error.path(), Some("/apisix/admin/routes/r1"));
+        assert!(server.requests().iter().all(|r| r.method == "GET"));
+        assert_eq!(server.requests().len(), 3);
+    }
+}
+// endregion: tests
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use crate::client::reqwest_generic::build_http_client;
+use crate::client::{AdminConnector, ControllerConnector};
+use crate::config::ApisixConfig;
+use crate::Result;
+
+/// Long-lived Apisix client
+///
+/// Holds a single pooled HTTP client, shared by the admin and control sub-clients.
+/// Cloning is cheap: clones share the same connection pool.
+/// # Example
+/// ```no_run
+/// # use apisix_admin_client::client::ApisixClient;
+/// # use apisix_admin_client::config::ApisixConfig;
+/// # async fn example() -> apisix_admin_client::error::ApisixLibError<()> {
+/// let client = ApisixClient::new(&ApisixConfig::default())?;
+/// let route = client.admin().routes().get("my-route").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApisixClient {
+    admin: AdminConnector,
+    control: ControllerConnector,
+}
+
+impl ApisixClient {
+    pub fn new(cfg: &ApisixConfig) -> Result<Self> {
+        let http = build_http_client(cfg)?;
+        Ok(ApisixClient {
+            admin: AdminConnector::new(cfg, http.clone()),
+            control: ControllerConnector::new(cfg, http),
+        })
+    }
+
+    /// Client shared by all the callers using the same configuration, built on first use
+    ///
+    /// Used by the free functions of the crate root so that they reuse connections across calls.
+    pub fn shared(cfg: &ApisixConfig) -> Result<Self> {
+        static CLIENTS: OnceLock<Mutex<HashMap<ApisixConfig, ApisixClient>>> = OnceLock::new();
+        let mut clients = CLIENTS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = clients.get(cfg) {
+            return Ok(client.clone());
+        }
+        let client = ApisixClient::new(cfg)?;
+        clients.insert(cfg.clone(), client.clone());
+        Ok(client)
+    }
+
+    /// Configuration the client was built with
+    pub fn config(&self) -> &ApisixConfig {
+        &self.admin.cfg
+    }
+
+    /// Admin API sub-client
+    pub fn admin(&self) -> &AdminConnector {
+        &self.admin
+    }
+
+    /// Control API sub-client
+    pub fn control(&self) -> &ControllerConnector {
+        &self.control
+    }
+}
+
+impl TryFrom<&ApisixConfig> for ApisixClient {
+    type Error = crate::error::ApisixClientError;
+
+    fn try_from(cfg: &ApisixConfig) -> Result<Self> {
+        ApisixClient::new(cfg)
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_client_resource_paths() {
+        let client = ApisixClient::new(&ApisixConfig::default()).unwrap();
+        assert_eq!(client.admin().routes().path(), "/apisix/admin/routes");
+        assert_eq!(client.admin().upstreams().path(), "/apisix/admin/upstreams");
+        assert_eq!(client.admin().consumers().path(), "/apisix/admin/consumers");
+        assert_eq!(client.clone().config().admin_url, "http://localhost:9180");
+    }
+}
+// endregion: tests
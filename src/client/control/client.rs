@@ -3,11 +3,12 @@
 //! Expose the internal state of APISIX.
 //! Control the behavior of a single, isolated APISIX data plane.
 
-use anyhow::Result;
+use reqwest::Client;
 use serde_json::Value;
 use tracing::{debug, instrument};
 use crate::client::reqwest_generic::{get, post_empty_body};
 use crate::config::ApisixConfig;
+use crate::Result;
 use crate::models::ctrl_responses::CtrlHealthCheckResponse;
 // region: Path definitions
 
@@ -16,38 +17,40 @@ fn get_health_check() -> String { "/v1/healthcheck".to_string() }
 fn get_garbage_collect() -> String { "/v1/gc".to_string() }
 // endregion: Path definitions
 
-#[derive(Debug, Default)]
+/// Control API sub-client, obtained through [crate::client::ApisixClient::control]
+#[derive(Debug, Default, Clone)]
 pub struct ControllerConnector {
     pub cfg: ApisixConfig,
+    pub(crate) http: Client,
 }
 
 impl ControllerConnector {
 
-    #[instrument]
-    pub async fn new(cfg: &ApisixConfig) -> Self {
+    pub (crate) fn new(cfg: &ApisixConfig, http: Client) -> Self {
         ControllerConnector {
             cfg: cfg.clone(),
+            http,
         }
     }
 
-    #[instrument]
+    #[instrument(skip(self))]
     pub async fn schema(&self) -> Result<Value> {
         let path = format!("{}{}", self.cfg.control_url, get_schema());
         debug!("controller_api::schema: {}", path);
-        get::<Value>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+        get::<Value>(&self.http, path.as_str(), self.cfg.admin_apikey.as_str()).await
     }
 
-    #[instrument]
+    #[instrument(skip(self))]
     pub async fn health_check(&self) -> Result<CtrlHealthCheckResponse> {
         let path = format!("{}{}", self.cfg.control_url, get_health_check());
         debug!("controller_api::health_check: {}", path);
-        get::<CtrlHealthCheckResponse>(path.as_str(), self.cfg.admin_apikey.as_str(), self.cfg.client_request_timeout).await
+        get::<CtrlHealthCheckResponse>(&self.http, path.as_str(), self.cfg.admin_apikey.as_str()).await
     }
 
-    #[instrument]
+    #[instrument(skip(self))]
     pub async fn gc(&self) -> Result<()> {
         let path = format!("{}{}", self.cfg.control_url, get_garbage_collect());
         debug!("controller_api::gc: {}", path);
-        post_empty_body(path.as_str(), self.cfg.admin_apikey.as_str(),self.cfg.client_request_timeout).await
+        post_empty_body(&self.http, path.as_str(), self.cfg.admin_apikey.as_str()).await
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,11 @@
 pub (super) mod reqwest_generic;
+mod apisix_client;
 pub (crate) mod admin;
 pub (crate) mod control;
+#[cfg(test)]
+#[allow(dead_code)]
+pub (crate) mod test_server;
 
-pub (crate) use admin::*;
-pub (crate) use control::*;
+pub use apisix_client::*;
+pub use admin::*;
+pub use control::*;
@@ -1,10 +1,12 @@
-use anyhow::{bail, Result};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
 use std::time::Duration;
-use reqwest::Response;
+use reqwest::{Client, Response};
 use tracing::debug;
+use crate::config::ApisixConfig;
+use crate::error::ApisixClientError;
+use crate::Result;
 
 const HEADER_CONTENT_TYPE: &str = "content-type";
 const HEADER_CONTENT_TYPE_DEFAULT: &str = "application/json";
@@ -13,124 +15,110 @@ const HEADER_API_KEY: &str = "X-API-KEY";
 const HEADER_USER_AGENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const HEADER_USER_AGENT_RUST_VERSION: &str = env!("CARGO_PKG_RUST_VERSION");
 
+/// Builds the pooled HTTP client shared by all requests of an [crate::client::ApisixClient]
+/// The request timeout is applied to every call made through the client
+pub fn build_http_client(cfg: &ApisixConfig) -> Result<Client> {
+    Client::builder()
+        .danger_accept_invalid_certs(cfg.accept_invalid_certs)
+        .timeout(Duration::from_millis(cfg.client_request_timeout))
+        .build()
+        .map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+}
+
 /// Generic get JWT based on APIKEY
 /// Not used for Apisix client
 #[allow(dead_code)]
-pub async fn get_token<R>(url: &str, apikey: &str, timeout_millis: Option<u64>) -> Result<R>
+pub async fn get_token<R>(client: &Client, url: &str, apikey: &str) -> Result<R>
 where
     R: DeserializeOwned,
 {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(std::time::Duration::from_millis(
-            timeout_millis.unwrap_or(30000),
-        ))
-        .build()?;
     client
         .get(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .send()
-        .await?
+        .await
+        .map_err(|e| transport_error(url, e))?
         .json::<R>()
         .await
-        .map_err(|e| e.into())
+        .map_err(|e| transport_error(url, e))
 }
 
 /// Generic HEAD request
-pub async fn head(url: &str, apikey: &str, timeout_millis: u64, ) -> Result<()>
+pub async fn head(client: &Client, url: &str, apikey: &str) -> Result<()>
 {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()?;
     let send_response = client
         .head(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .send()
-        .await?;
+        .await
+        .map_err(|e| transport_error(url, e))?;
     let status = send_response.status().as_u16();
     match status {
         200..=299 => Ok(()),
-        _ => {
-            let response = send_response.bytes().await?;
-            let text = String::from_utf8(response.to_vec()).unwrap();
-            debug!("{:?}", text);
-            bail!(text)
-        }
+        _ => Err(error_response(url, send_response).await),
     }
 }
 
 /// Generic GET request
-/// Connection pooling is provided in `reqwest`
-pub async fn get<R>(url: &str, apikey: &str, timeout_millis: u64, ) -> Result<R>
+/// Connection pooling is provided by the shared `reqwest` client
+pub async fn get<R>(client: &Client, url: &str, apikey: &str) -> Result<R>
 where
     R: DeserializeOwned,
 {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()?;
     let send_response = client
         .get(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .send()
-        .await?;
+        .await
+        .map_err(|e| transport_error(url, e))?;
     let status = send_response.status().as_u16();
     match status {
-        200..=299 => send_response.json::<R>().await.map_err(|e| e.into()),
-        _ => {
-            let response = send_response.bytes().await?;
-            let text = String::from_utf8(response.to_vec()).unwrap();
-            debug!("{:?}", text);
-            bail!(text)
-        }
+        200..=299 => decode::<R>(url, send_response).await,
+        _ => Err(error_response(url, send_response).await),
     }
 }
 
 /// Generic DELETE request
-/// Connection pooling is provided in `reqwest`
+/// Connection pooling is provided by the shared `reqwest` client
 pub async fn delete(
+    client: &Client,
     url: &str,
     apikey: &str,
-    timeout_millis: u64,
-) -> Result<Response> {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()?;
-    client
+) -> Result<()> {
+    let send_response = client
         .delete(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .send()
-        .await.map_err(|e| e.into())
+        .await
+        .map_err(|e| transport_error(url, e))?;
+    let status = send_response.status().as_u16();
+    match status {
+        200..=299 => Ok(()),
+        _ => Err(error_response(url, send_response).await),
+    }
 }
 
 /// Generic POST request
-/// Connection pooling is provided in `reqwest`
+/// Connection pooling is provided by the shared `reqwest` client
 #[allow(dead_code)]
 pub async fn post<T, R>(
+    client: &Client,
     url: &str,
     apikey: &str,
     req: &T,
-    timeout_millis: u64,
 ) -> Result<R>
 where
     T: Serialize + Debug,
     R: DeserializeOwned,
 {
-    let client = reqwest::Client::builder()
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()
-        .unwrap();
     let send_response = client
         .post(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
@@ -138,68 +126,50 @@ where
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .json(req)
         .send()
-        .await?;
+        .await
+        .map_err(|e| transport_error(url, e))?;
     let status = send_response.status().as_u16();
     match status {
-        200..=299 => send_response.json::<R>().await.map_err(|e| e.into()),
-        _ => {
-            let response = send_response.bytes().await?;
-            let text = String::from_utf8(response.to_vec()).unwrap();
-            debug!("{:?}", text);
-            bail!(text)
-        }
+        200..=299 => decode::<R>(url, send_response).await,
+        _ => Err(error_response(url, send_response).await),
     }
 }
 
 /// Generic POST request
-/// Connection pooling is provided in `reqwest`
+/// Connection pooling is provided by the shared `reqwest` client
 pub async fn post_empty_body(
+    client: &Client,
     url: &str,
     apikey: &str,
-    timeout_millis: u64,
 ) -> Result<()>
 {
-    let client = reqwest::Client::builder()
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()
-        .unwrap();
     let send_response = client
         .post(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .send()
-        .await?;
+        .await
+        .map_err(|e| transport_error(url, e))?;
     let status = send_response.status().as_u16();
     match status {
         200..=299 => Ok(()),
-        _ => {
-            let response = send_response.bytes().await?;
-            let text = String::from_utf8(response.to_vec()).unwrap();
-            debug!("{:?}", text);
-            bail!(text)
-        }
+        _ => Err(error_response(url, send_response).await),
     }
 }
 
 // Generic POST request
-/// Connection pooling is provided in `reqwest`
+/// Connection pooling is provided by the shared `reqwest` client
 #[allow(dead_code)]
 pub async fn post_json_value<T>(
+    client: &Client,
     url: &str,
     apikey: &str,
     req: &T,
-    timeout_millis: u64,
 ) -> Result<serde_json::Value>
 where
     T: Serialize + Debug,
 {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()
-        .unwrap();
     let send_response = client
         .post(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
@@ -207,50 +177,104 @@ where
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .json(req)
         .send()
-        .await?;
+        .await
+        .map_err(|e| transport_error(url, e))?;
     let status = send_response.status().as_u16();
     match status {
-        200..=299 => send_response
-            .json::<serde_json::Value>()
-            .await
-            .map_err(|e| e.into()),
-        _ => {
-            let response = send_response.bytes().await?;
-            let text = String::from_utf8(response.to_vec()).unwrap();
-            debug!("{:?}", text);
-            bail!(text)
-        }
+        200..=299 => decode::<serde_json::Value>(url, send_response).await,
+        _ => Err(error_response(url, send_response).await),
     }
 }
 
 /// Generic PUT request
-/// Connection pooling is provided in `reqwest`
+/// Connection pooling is provided by the shared `reqwest` client
 pub async fn put<T, R>(
+    client: &Client,
     url: &str,
     apikey: &str,
     req: &T,
-    timeout_millis: u64,
 ) -> Result<R>
 where
     T: Serialize + Debug,
     R: DeserializeOwned,
 {
-    let client = reqwest::Client::builder()
-        //.danger_accept_invalid_certs(true)
-        .timeout(Duration::from_millis(timeout_millis))
-        .build()
-        .unwrap();
-    client
+    let send_response = client
         .put(url)
         .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
         .header(HEADER_API_KEY, apikey)
         .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
         .json(req)
         .send()
-        .await?
-        .json::<R>()
         .await
-        .map_err(|e| e.into())
+        .map_err(|e| transport_error(url, e))?;
+    let status = send_response.status().as_u16();
+    match status {
+        200..=299 => decode::<R>(url, send_response).await,
+        _ => Err(error_response(url, send_response).await),
+    }
+}
+
+/// Generic PATCH request, `req` is a partial document merged into the stored object
+/// Connection pooling is provided by the shared `reqwest` client
+pub async fn patch<T, R>(
+    client: &Client,
+    url: &str,
+    apikey: &str,
+    req: &T,
+) -> Result<R>
+where
+    T: Serialize + Debug,
+    R: DeserializeOwned,
+{
+    let send_response = client
+        .patch(url)
+        .header(HEADER_CONTENT_TYPE, HEADER_CONTENT_TYPE_DEFAULT)
+        .header(HEADER_API_KEY, apikey)
+        .header(HEADER_USER_AGENT, format!("apisix-admin-client/{:?}/rust/{:?}",HEADER_USER_AGENT_VERSION, HEADER_USER_AGENT_RUST_VERSION))
+        .json(req)
+        .send()
+        .await
+        .map_err(|e| transport_error(url, e))?;
+    let status = send_response.status().as_u16();
+    match status {
+        200..=299 => decode::<R>(url, send_response).await,
+        _ => Err(error_response(url, send_response).await),
+    }
+}
+
+/// Path of the request url, used as context in errors
+fn request_path(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .map(|u| u.path().to_string())
+        .unwrap_or_else(|_| url.to_string())
+}
+
+fn transport_error(url: &str, source: reqwest::Error) -> ApisixClientError {
+    ApisixClientError::Transport { path: request_path(url), source }
 }
 
+/// Maps a non-success response to a typed error, see [ApisixClientError::from_response]
+async fn error_response(url: &str, response: Response) -> ApisixClientError {
+    let status = response.status().as_u16();
+    match response.bytes().await {
+        Ok(body) => {
+            debug!("{:?}", String::from_utf8_lossy(&body));
+            ApisixClientError::from_response(status, request_path(url).as_str(), &body)
+        },
+        Err(e) => transport_error(url, e),
+    }
+}
 
+/// Decodes the response body into the expected model
+async fn decode<R>(url: &str, response: Response) -> Result<R>
+where
+    R: DeserializeOwned,
+{
+    let status = response.status().as_u16();
+    let body = response.bytes().await.map_err(|e| transport_error(url, e))?;
+    serde_json::from_slice::<R>(&body).map_err(|e| ApisixClientError::Decode {
+        status,
+        message: e.to_string(),
+        path: request_path(url),
+    })
+}
@@ -0,0 +1,153 @@
+//! Minimal HTTP server standing in for the Admin API in unit tests
+//!
+//! Responses are registered per method and path (the query string is ignored unless the registered
+//! path has one), they are served in order and the last one is repeated. Unregistered requests get a 404.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use crate::client::AdminConnector;
+use crate::config::ApisixConfig;
+
+/// Request received by the [TestServer]
+#[derive(Debug, Clone)]
+pub(crate) struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+#[derive(Default)]
+struct State {
+    responses: HashMap<(String, String), Vec<(u16, Value)>>,
+    requests: Vec<Recorded>,
+    in_flight: usize,
+    max_in_flight: usize,
+    delay: Duration,
+    path_delays: HashMap<String, Duration>,
+}
+
+pub(crate) struct TestServer {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl TestServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let shared = state.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, shared.clone()));
+            }
+        });
+        TestServer { url, state }
+    }
+
+    /// Connector on the server, with the default configuration otherwise
+    pub fn connector(&self) -> AdminConnector {
+        let cfg = ApisixConfig {
+            admin_url: self.url.clone(),
+            ..ApisixConfig::default()
+        };
+        AdminConnector::new(&cfg, Client::new())
+    }
+
+    /// Queues a response to `method path`, e.g. `("GET", "/apisix/admin/routes/r1")`
+    pub fn on(&self, method: &str, path: &str, status: u16, body: Value) -> &Self {
+        self.state.lock().unwrap().responses.entry((method.to_string(), path.to_string())).or_default().push((status, body));
+        self
+    }
+
+    /// Delay before answering each request, to observe concurrent requests
+    pub fn with_delay(&self, delay: Duration) -> &Self {
+        self.state.lock().unwrap().delay = delay;
+        self
+    }
+
+    /// Delay before answering the requests on `path`, overriding [TestServer::with_delay]
+    pub fn with_path_delay(&self, path: &str, delay: Duration) -> &Self {
+        self.state.lock().unwrap().path_delays.insert(path.to_string(), delay);
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// `method path` of the requests received, in order
+    pub fn calls(&self) -> Vec<String> {
+        self.requests().iter().map(|r| format!("{} {}", r.method, r.path)).collect()
+    }
+
+    /// Largest number of requests handled at the same time
+    pub fn max_in_flight(&self) -> usize {
+        self.state.lock().unwrap().max_in_flight
+    }
+}
+
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let mut buffer = vec![];
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let Ok(read) = stream.read(&mut chunk).await else { return };
+        if read == 0 {
+            return;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let content_length = head.lines()
+        .filter_map(|l| l.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    while buffer.len() < header_end + content_length {
+        let Ok(read) = stream.read(&mut chunk).await else { return };
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let mut request_line = head.lines().next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+    let body = serde_json::from_slice(&buffer[header_end..]).ok();
+
+    let delay = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(Recorded { method: method.clone(), path: path.clone(), body });
+        state.in_flight += 1;
+        state.max_in_flight = state.max_in_flight.max(state.in_flight);
+        state.path_delays.get(&path).copied().unwrap_or(state.delay)
+    };
+    tokio::time::sleep(delay).await;
+    let (status, body) = {
+        let mut state = state.lock().unwrap();
+        state.in_flight -= 1;
+        let mut key = (method, path.clone());
+        if !state.responses.contains_key(&key) {
+            key.1 = path.split('?').next().unwrap_or_default().to_string();
+        }
+        match state.responses.get_mut(&key) {
+            Some(q) if q.len() > 1 => q.remove(0),
+            Some(q) if !q.is_empty() => q[0].clone(),
+            _ => (404, json!({"error_msg": "not found"})),
+        }
+    };
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, body.len(), body
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
@@ -1,149 +1,648 @@
+use std::path::PathBuf;
+use serde_json::Value;
 use tracing::instrument;
 use crate::RouteRequest;
 use crate::ApisixRoute;
 use crate::ServiceRequest;
 use crate::ApisixService;
-use crate::client::AdminConnector;
+use crate::client::{ApisixClient, ApplyOptions, ApplyReport, BulkReport};
 use crate::config::ApisixConfig;
-use crate::error::ApisixClientError::InvalidRequest;
 use crate::models::common::{ListResponse, TypedItem};
-use crate::models::{ApisixConsumer, ApisixConsumerGroup, ApisixUpstream, ConsumerGroupRequest, ConsumerRequest, UpstreamRequest};
+use crate::models::{AdminObject, ResourceKind, DesiredState, Plan, Snapshot, DriftReport, BackupArchive, StandaloneConfig, LabelSelector, ListQuery, ApisixAwsSecret, ApisixConsumer, ApisixProto, ProtoRequest, ApisixLoggerMetadata, LoggerMetadataRequest, LoggerPlugin, ApisixConsumerGroup, ApisixCredential, ApisixGlobalRule, ApisixPluginConfig, ApisixSsl, ApisixStreamRoute, ApisixUpstream, ApisixVaultSecret, AwsSecretRequest, ConsumerGroupRequest, ConsumerRequest, CredentialRequest, GlobalRuleRequest, PluginConfigRequest, SslRequest, StreamRouteRequest, UpstreamRequest, VaultSecretRequest};
 
 type Result<T> = std::result::Result<T, crate::error::ApisixClientError>;
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_check_version(cfg: &ApisixConfig) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.check_version().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().check_version().await
 }
 
 // region: upstream
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_upstreams(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixUpstream>>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_upstreams().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().list().await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_upstream(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixUpstream>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_upstream(id).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().get(id).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_create_upstream_with_id(cfg: &ApisixConfig, id: &str, req: &UpstreamRequest) -> Result<TypedItem<ApisixUpstream>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.create_upstream_with_id(id, req).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().put(id, req).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_delete_upstream(cfg: &ApisixConfig, id: &str) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.delete_upstream(id).await.map(|_| ()).map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().delete(id).await
 }
 // endregion: upstream
 
 // region: service
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_services(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixService>>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_services().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().list().await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_service(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixService>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_service(id).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().get(id).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_create_service_with_id(cfg: &ApisixConfig, id: &str, req: &ServiceRequest) -> Result<TypedItem<ApisixService>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.create_service_with_id(id, req).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().put(id, req).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_delete_service(cfg: &ApisixConfig, id: &str) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.delete_service(id).await.map(|_| ()).map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().delete(id).await
 }
 // endregion: service
 
 // region: route
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_routes(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixRoute>>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_routes().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().list().await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_route(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixRoute>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_route(id).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().get(id).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_create_route_with_id(cfg: &ApisixConfig, id: &str, req: &RouteRequest) -> Result<TypedItem<ApisixRoute>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.create_route_with_id(id, req).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().put(id, req).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_delete_route(cfg: &ApisixConfig, id: &str) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.delete_route(id).await.map(|_| ()).map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().delete(id).await
 }
 // endregion: route
 
 // region: consumer group
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_consumer_groups(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixConsumerGroup>>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_consumer_groups().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().list().await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_consumer_group(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixConsumerGroup>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_consumer_group(id).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().get(id).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_create_consumer_group_with_id(cfg: &ApisixConfig, id: &str, req: &ConsumerGroupRequest) -> Result<TypedItem<ApisixConsumerGroup>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.create_consumer_group_with_id(id, req).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().put(id, req).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_delete_consumer_group(cfg: &ApisixConfig, id: &str) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.delete_consumer_group(id).await.map(|_| ()).map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().delete(id).await
 }
 // endregion: consumer group
 
 // region: consumer
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_consumers(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_consumers().await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().list().await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_get_consumer(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixConsumer>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.get_consumer(id).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().get(id).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_create_consumer(cfg: &ApisixConfig, id: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.create_consumer(id, req).await.map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().put(id, req).await
 }
 
 #[instrument(skip_all)]
 pub (crate) async fn api_admin_delete_consumer(cfg: &ApisixConfig, id: &str) -> Result<()> {
-    let ac: AdminConnector =  AdminConnector::new(cfg).await;
-    ac.delete_consumer(id).await.map(|_| ()).map_err(|e| InvalidRequest(e.to_string()))
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().delete(id).await
 }
 
-// endregion: consumer
\ No newline at end of file
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_or_create_consumer(cfg: &ApisixConfig, id: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().get_or_create(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_rename_consumer(cfg: &ApisixConfig, id: &str, new_id: &str) -> Result<TypedItem<ApisixConsumer>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().rename(id, new_id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumer_credentials(cfg: &ApisixConfig, username: &str) -> Result<ListResponse<TypedItem<ApisixCredential>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().credentials(username).list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumer_credential(cfg: &ApisixConfig, username: &str, id: &str) -> Result<TypedItem<ApisixCredential>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().credentials(username).get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_consumer_credential_with_id(cfg: &ApisixConfig, username: &str, id: &str, req: &CredentialRequest) -> Result<TypedItem<ApisixCredential>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().credentials(username).put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_consumer_credential(cfg: &ApisixConfig, username: &str, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().credentials(username).delete(id).await
+}
+// endregion: consumer
+
+// region: ssl
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_ssls(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixSsl>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_ssl(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixSsl>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_ssl_with_id(cfg: &ApisixConfig, id: &str, req: &SslRequest) -> Result<TypedItem<ApisixSsl>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_ssl(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().delete(id).await
+}
+// endregion: ssl
+
+// region: global rule
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_global_rules(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixGlobalRule>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_global_rule(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixGlobalRule>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_global_rule_with_id(cfg: &ApisixConfig, id: &str, req: &GlobalRuleRequest) -> Result<TypedItem<ApisixGlobalRule>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_global_rule(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().delete(id).await
+}
+// endregion: global rule
+
+// region: plugin config
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_plugin_configs(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixPluginConfig>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_plugin_config(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixPluginConfig>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_plugin_config_with_id(cfg: &ApisixConfig, id: &str, req: &PluginConfigRequest) -> Result<TypedItem<ApisixPluginConfig>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_plugin_config(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().delete(id).await
+}
+// endregion: plugin config
+
+// region: stream route
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_stream_routes(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixStreamRoute>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().stream_routes().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_stream_route(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixStreamRoute>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().stream_routes().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_stream_route_with_id(cfg: &ApisixConfig, id: &str, req: &StreamRouteRequest) -> Result<TypedItem<ApisixStreamRoute>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().stream_routes().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_stream_route(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().stream_routes().delete(id).await
+}
+// endregion: stream route
+
+// region: secret
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_vault_secrets(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixVaultSecret>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().vault_secrets().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_vault_secret(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixVaultSecret>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().vault_secrets().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_vault_secret_with_id(cfg: &ApisixConfig, id: &str, req: &VaultSecretRequest) -> Result<TypedItem<ApisixVaultSecret>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().vault_secrets().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_vault_secret(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().vault_secrets().delete(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_aws_secrets(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixAwsSecret>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().aws_secrets().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_aws_secret(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixAwsSecret>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().aws_secrets().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_aws_secret_with_id(cfg: &ApisixConfig, id: &str, req: &AwsSecretRequest) -> Result<TypedItem<ApisixAwsSecret>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().aws_secrets().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_aws_secret(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().aws_secrets().delete(id).await
+}
+// endregion: secret
+
+// region: plugin metadata
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_logger_metadata(cfg: &ApisixConfig, plugin: &LoggerPlugin) -> Result<TypedItem<ApisixLoggerMetadata>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_metadata().get_logger(plugin).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_logger_metadata(cfg: &ApisixConfig, plugin: &LoggerPlugin, req: &LoggerMetadataRequest) -> Result<TypedItem<ApisixLoggerMetadata>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_metadata().put_logger(plugin, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str) -> Result<TypedItem<Value>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_metadata().get(plugin_name).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str, metadata: &Value) -> Result<TypedItem<Value>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_metadata().put(plugin_name, metadata).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_metadata().delete(plugin_name).await
+}
+// endregion: plugin metadata
+
+// region: proto
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_protos(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixProto>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().protos().list().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_proto(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixProto>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().protos().get(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_create_proto_with_id(cfg: &ApisixConfig, id: &str, req: &ProtoRequest) -> Result<TypedItem<ApisixProto>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().protos().put(id, req).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_proto(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().protos().delete(id).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_upload_protos(cfg: &ApisixConfig, paths: &[PathBuf]) -> Result<Vec<String>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upload_protos(paths).await
+}
+// endregion: proto
+
+// region: patch
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_upstream(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixUpstream>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_service(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixService>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_route(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixRoute>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_consumer_group(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixConsumerGroup>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_ssl(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixSsl>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_global_rule(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixGlobalRule>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_plugin_config(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixPluginConfig>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().patch(id, doc).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_patch_route_sub_path(cfg: &ApisixConfig, id: &str, sub_path: &str, value: &Value) -> Result<TypedItem<ApisixRoute>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().patch_sub_path(id, sub_path, value).await
+}
+// endregion: patch
+
+// region: list query
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_upstreams_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixUpstream>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_services_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixService>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_routes_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixRoute>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumer_groups_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixConsumerGroup>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumers_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_ssls_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixSsl>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().ssls().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_global_rules_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixGlobalRule>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().global_rules().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_plugin_configs_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixPluginConfig>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plugin_configs().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_stream_routes_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixStreamRoute>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().stream_routes().list_with(query).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_protos_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixProto>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().protos().list_with(query).await
+}
+// endregion: list query
+
+// region: label selector
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_upstreams_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixUpstream>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().upstreams().list_selected(selector).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_services_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixService>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().services().list_selected(selector).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_routes_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixRoute>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().routes().list_selected(selector).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumer_groups_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixConsumerGroup>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumer_groups().list_selected(selector).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_get_consumers_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixConsumer>>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().consumers().list_selected(selector).await
+}
+// endregion: label selector
+
+// region: bulk
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_bulk_apply(cfg: &ApisixConfig, objects: &[AdminObject], concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    Ok(client.admin().bulk_apply(objects, concurrency).await)
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_bulk_delete(cfg: &ApisixConfig, targets: &[(ResourceKind, String)], concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    Ok(client.admin().bulk_delete(targets, concurrency).await)
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_bulk_export(cfg: &ApisixConfig, kinds: &[ResourceKind], concurrency: usize) -> Result<Vec<AdminObject>> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().bulk_export(kinds, concurrency).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_selected(cfg: &ApisixConfig, kind: ResourceKind, selector: &LabelSelector, concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().delete_selected(kind, selector, concurrency).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_by_id_prefix(cfg: &ApisixConfig, kind: ResourceKind, prefix: &str, concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().delete_by_id_prefix(kind, prefix, concurrency).await
+}
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_apply_ordered(cfg: &ApisixConfig, objects: &[AdminObject], concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    Ok(client.admin().apply_ordered(objects, concurrency).await)
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_ordered(cfg: &ApisixConfig, targets: &[(ResourceKind, String)], concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    Ok(client.admin().delete_ordered(targets, concurrency).await)
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_delete_cascade(cfg: &ApisixConfig, kind: ResourceKind, id: &str, concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().delete_cascade(kind, id, concurrency).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_export_standalone(cfg: &ApisixConfig, concurrency: usize) -> Result<StandaloneConfig> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().export_standalone(concurrency).await
+}
+// endregion: bulk
+
+// region: declarative
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_plan(cfg: &ApisixConfig, desired: &DesiredState) -> Result<Plan> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().plan(desired).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_apply(cfg: &ApisixConfig, plan: &Plan, opts: &ApplyOptions) -> Result<ApplyReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().apply(plan, opts).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_snapshot(cfg: &ApisixConfig) -> Result<Snapshot> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().snapshot().await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_drift_from_snapshot(cfg: &ApisixConfig, snapshot: &Snapshot) -> Result<DriftReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().drift_from_snapshot(snapshot).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_drift_from_desired(cfg: &ApisixConfig, desired: &DesiredState) -> Result<DriftReport> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().drift_from_desired(desired).await
+}
+// endregion: declarative
+
+// region: backup
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_backup(cfg: &ApisixConfig, concurrency: usize) -> Result<BackupArchive> {
+    let client = ApisixClient::shared(cfg)?;
+    client.admin().backup(concurrency).await
+}
+
+#[instrument(skip_all)]
+pub (crate) async fn api_admin_restore(cfg: &ApisixConfig, archive: &BackupArchive, concurrency: usize) -> Result<BulkReport> {
+    let client = ApisixClient::shared(cfg)?;
+    Ok(client.admin().restore(archive, concurrency).await)
+}
+// endregion: backup
@@ -15,13 +15,15 @@ pub fn config() -> &'static ApisixConfig {
 }
 
 /// Apisix Client Configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ApisixConfig {
     pub url: String,
     pub admin_url: String,
     pub control_url: String,
     pub admin_apikey: String,
     pub client_request_timeout: u64,
+    /// Accepts invalid or self-signed TLS certificates, for development setups only
+    pub accept_invalid_certs: bool,
 }
 
 impl From<ApisixConfig> for ApisixConfigBuilder {
@@ -32,6 +34,7 @@ impl From<ApisixConfig> for ApisixConfigBuilder {
             control_url: Some(config.control_url),
             admin_apikey: Some(config.admin_apikey),
             client_request_timeout: Some(config.client_request_timeout),
+            accept_invalid_certs: config.accept_invalid_certs,
         }
     }
 }
@@ -57,6 +60,7 @@ pub struct ApisixConfigBuilder {
     control_url: Option<String>,
     admin_apikey: Option<String>,
     client_request_timeout: Option<u64>,
+    accept_invalid_certs: bool,
 }
 
 impl ApisixConfigBuilder {
@@ -89,6 +93,12 @@ impl ApisixConfigBuilder {
         self
     }
 
+    /// Accepts invalid or self-signed TLS certificates, for development setups only
+    pub fn with_accept_invalid_certs(&mut self, accept_invalid_certs: bool) -> &mut Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
     pub fn build(&self) -> Result<ApisixConfig> {
         Ok(ApisixConfig {
             url: self.url.clone().ok_or(ApisixClientError::ConfigMissingException("url"))?,
@@ -96,6 +106,7 @@ impl ApisixConfigBuilder {
             control_url: self.control_url.clone().ok_or(ApisixClientError::ConfigMissingException("control_url"))?,
             admin_apikey: self.admin_apikey.clone().ok_or(ApisixClientError::ConfigMissingException("admin_path"))?,
             client_request_timeout: self.client_request_timeout.ok_or(ApisixClientError::ConfigMissingException("admin_path"))?,
+            accept_invalid_certs: self.accept_invalid_certs,
         })
     }
 
@@ -112,6 +123,7 @@ impl Default for ApisixConfig {
             control_url: "http://localhost:9090".to_string(),
             admin_apikey: "edd1c9f034335f136f87ad84b625c8f1".to_string(),
             client_request_timeout: 30000,
+            accept_invalid_certs: false,
         }
     }
 }
@@ -131,6 +143,7 @@ impl ApisixConfig {
             control_url: get_env("APISIX_CONTROL_URL")?,
             admin_apikey: get_env("APISIX_ADMIN_API_KEY")?,
             client_request_timeout: get_env_u64("CLIENT_REQ_NETWORK_TIMEOUT_MILLIS")?,
+            accept_invalid_certs: env::var("APISIX_ACCEPT_INVALID_CERTS").is_ok_and(|v| v == "true"),
         })
     }
 }
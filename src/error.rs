@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub type ApisixLibError<T> = Result<T, ApisixClientError>;
@@ -25,5 +26,221 @@ pub enum ApisixClientError {
 
     /// Authentication Exception
     #[error("Authentication Exception")]
-    AuthenticationException
-}
\ No newline at end of file
+    AuthenticationException,
+
+    /// The requested object does not exist (404)
+    #[error("{kind} not found: {id} ({path})")]
+    NotFound {
+        kind: String,
+        id: String,
+        path: String,
+        error: Option<ApisixErrorBody>,
+    },
+
+    /// The admin key is missing or invalid (401/403)
+    #[error("Unauthorized ({status}): {path}")]
+    Unauthorized {
+        status: u16,
+        path: String,
+        error: Option<ApisixErrorBody>,
+    },
+
+    /// The object was rejected by the Apisix schema validation (400)
+    #[error("Schema violation on {path}: {message}")]
+    SchemaViolation {
+        message: String,
+        path: String,
+        error: Option<ApisixErrorBody>,
+    },
+
+    /// Apisix answered 409, or 400 when deleting an object still referenced by others, e.g.
+    /// `can not delete this upstream, route [1] is still using it now`;
+    /// without status when the object was modified concurrently, see [crate::client::AdminResource::update_with]
+    #[error("Conflict on {path}: {message}")]
+    Conflict {
+        status: Option<u16>,
+        message: String,
+        path: String,
+        error: Option<ApisixErrorBody>,
+    },
+
+    /// Any other non-success status returned by Apisix
+    #[error("Apisix returned {status} on {path}: {message}")]
+    ApiError {
+        status: u16,
+        message: String,
+        path: String,
+        error: Option<ApisixErrorBody>,
+    },
+
+    /// The request could not be sent or no response was received (connection, timeout, ...)
+    #[error("Transport error on {path}: {source}")]
+    Transport {
+        path: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// The response body could not be decoded into the expected model
+    #[error("Decode error on {path}: {message}")]
+    Decode {
+        status: u16,
+        message: String,
+        path: String,
+    },
+}
+
+/// Error body returned by the Admin API, e.g. `{"error_msg": "failed to check token"}`,
+/// the 404 responses use `{"message": "Not found"}` instead
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApisixErrorBody {
+    #[serde(alias = "message")]
+    pub error_msg: Option<String>,
+}
+
+impl ApisixClientError {
+    /// Maps a non-success response of the Admin or Control API to a typed error
+    /// `path` is the request path, `body` the raw response body
+    pub(crate) fn from_response(status: u16, path: &str, body: &[u8]) -> Self {
+        let error = serde_json::from_slice::<ApisixErrorBody>(body).ok();
+        let message = error
+            .as_ref()
+            .and_then(|e| e.error_msg.clone())
+            .unwrap_or_else(|| String::from_utf8_lossy(body).to_string());
+        let path = path.to_string();
+        match status {
+            400 if message.starts_with("can not delete") => ApisixClientError::Conflict { status: Some(status), message, path, error },
+            400 => ApisixClientError::SchemaViolation { message, path, error },
+            401 | 403 => ApisixClientError::Unauthorized { status, path, error },
+            404 => {
+                let (kind, id) = split_resource_path(&path);
+                ApisixClientError::NotFound { kind, id, path, error }
+            },
+            409 => ApisixClientError::Conflict { status: Some(status), message, path, error },
+            _ => ApisixClientError::ApiError { status, message, path, error },
+        }
+    }
+
+    /// HTTP status code returned by Apisix, when a response was received
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApisixClientError::NotFound { .. } => Some(404),
+            ApisixClientError::Unauthorized { status, .. } => Some(*status),
+            ApisixClientError::SchemaViolation { .. } => Some(400),
+            ApisixClientError::Conflict { status, .. } => *status,
+            ApisixClientError::ApiError { status, .. } => Some(*status),
+            ApisixClientError::Decode { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Request path the error relates to
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            ApisixClientError::NotFound { path, .. }
+            | ApisixClientError::Unauthorized { path, .. }
+            | ApisixClientError::SchemaViolation { path, .. }
+            | ApisixClientError::Conflict { path, .. }
+            | ApisixClientError::ApiError { path, .. }
+            | ApisixClientError::Transport { path, .. }
+            | ApisixClientError::Decode { path, .. } => Some(path.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Parsed Apisix error body (`error_msg`), when one was returned
+    pub fn apisix_error(&self) -> Option<&ApisixErrorBody> {
+        match self {
+            ApisixClientError::NotFound { error, .. }
+            | ApisixClientError::Unauthorized { error, .. }
+            | ApisixClientError::SchemaViolation { error, .. }
+            | ApisixClientError::Conflict { error, .. }
+            | ApisixClientError::ApiError { error, .. } => error.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ApisixClientError::NotFound { .. })
+    }
+
+    /// True when the object is in use or was modified concurrently
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, ApisixClientError::Conflict { .. })
+    }
+
+    /// True when the request timed out before Apisix answered
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, ApisixClientError::Transport { source, .. } if source.is_timeout())
+    }
+}
+
+/// Splits an admin path like `/apisix/admin/routes/my-route` in its resource kind and id
+fn split_resource_path(path: &str) -> (String, String) {
+    let path = path.split('?').next().unwrap_or_default();
+    let resource = path
+        .split_once("/apisix/admin/")
+        .map(|(_, r)| r)
+        .unwrap_or(path.trim_start_matches('/'));
+    match resource.split_once('/') {
+        Some((kind, id)) => (kind.to_string(), id.to_string()),
+        None => (resource.to_string(), String::new()),
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_from_response_not_found() {
+        let body = r#"{"error_msg":"Key not found"}"#;
+        let e = ApisixClientError::from_response(404, "http://localhost:9180/apisix/admin/routes/my-route", body.as_bytes());
+        match &e {
+            ApisixClientError::NotFound { kind, id, .. } => {
+                assert_eq!(kind, "routes");
+                assert_eq!(id, "my-route");
+            },
+            _ => panic!("expected NotFound, got {:?}", e),
+        }
+        assert_eq!(e.status(), Some(404));
+        assert_eq!(e.apisix_error().unwrap().error_msg.as_deref(), Some("Key not found"));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_from_response_schema_violation() {
+        let body = r#"{"error_msg":"invalid configuration: property \"uri\" validation failed"}"#;
+        let e = ApisixClientError::from_response(400, "/apisix/admin/routes/r1", body.as_bytes());
+        assert!(matches!(&e, ApisixClientError::SchemaViolation { message, .. } if message.starts_with("invalid configuration")));
+        let e = ApisixClientError::from_response(401, "/apisix/admin/routes", b"<html>401</html>");
+        assert!(matches!(e, ApisixClientError::Unauthorized { status: 401, error: None, .. }));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_conflicts() {
+        let e = ApisixClientError::from_response(409, "/apisix/admin/upstreams/u1", br#"{"error_msg":"can not delete this upstream"}"#);
+        assert!(e.is_conflict());
+        assert_eq!(e.path(), Some("/apisix/admin/upstreams/u1"));
+        assert_eq!(e.status(), Some(409));
+        let body = br#"{"error_msg":"can not delete this upstream, route [1] is still using it now"}"#;
+        let e = ApisixClientError::from_response(400, "/apisix/admin/upstreams/u1", body);
+        assert!(e.is_conflict());
+        assert_eq!(e.status(), Some(400));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_from_response_message_body() {
+        let e = ApisixClientError::from_response(404, "/apisix/admin/routes/r1", br#"{"message":"Not found"}"#);
+        assert_eq!(e.apisix_error().unwrap().error_msg.as_deref(), Some("Not found"));
+        let e = ApisixClientError::from_response(500, "/apisix/admin/routes/r1", br#"{"message":"boom"}"#);
+        assert!(matches!(e, ApisixClientError::ApiError { message, .. } if message == "boom"));
+    }
+}
+// endregion: tests
@@ -4,6 +4,7 @@
 //!
 //! Maintained by [Trust1Team](https://trust1team.com) for [Apisix](https://apisix.apache.org/)
 
+use std::path::PathBuf;
 use serde_json::Value;
 use crate::config::ApisixConfig;
 pub mod client;
@@ -16,10 +17,13 @@ pub mod client_ctrl_impl;
 
 type Result<T> = std::result::Result<T, crate::error::ApisixClientError>;
 
+pub use crate::client::ApisixClient;
+
 /// Common models are exposed
 use crate::client_admin_impl::{api_admin_check_version, api_admin_get_services, api_admin_get_upstreams};
 use crate::client_ctrl_impl::api_ctrl_schema;
-use crate::models::{ApisixConsumer, ApisixConsumerGroup, ApisixRoute, ApisixService, ApisixUpstream, ConsumerGroupRequest, ConsumerRequest, RouteRequest, ServiceRequest};
+use crate::models::{ApisixConsumer, ApisixConsumerGroup, ApisixRoute, ApisixService, ApisixUpstream, ConsumerGroupRequest, ConsumerRequest, RouteRequest, ServiceRequest, ApisixSsl, SslRequest, ApisixGlobalRule, GlobalRuleRequest, ApisixPluginConfig, PluginConfigRequest, ApisixStreamRoute, StreamRouteRequest, ApisixCredential, CredentialRequest, ApisixVaultSecret, VaultSecretRequest, ApisixAwsSecret, AwsSecretRequest, ApisixLoggerMetadata, LoggerMetadataRequest, LoggerPlugin, ApisixProto, ProtoRequest, ListQuery, LabelSelector, AdminObject, ResourceKind, DesiredState, Plan, Snapshot, DriftReport, BackupArchive, StandaloneConfig};
+use crate::client::{ApplyOptions, ApplyReport, BulkReport};
 use crate::models::common::{ListResponse, TypedItem};
 use crate::models::ctrl_responses::CtrlHealthCheckResponse;
 use crate::models::UpstreamRequest;
@@ -149,15 +153,416 @@ pub async fn admin_get_consumer(cfg: &ApisixConfig, username: &str) -> Result<Ty
     client_admin_impl::api_admin_get_consumer(cfg, username).await
 }
 
-/// Create new Consumer
+/// Creates or updates a Consumer with the specified username
+/// The username in the request must be empty or equal to `id`
 pub async fn admin_create_consumer_with_name(cfg: &ApisixConfig, id: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
     client_admin_impl::api_admin_create_consumer(cfg, id, req).await
 }
 
+/// Fetches the Consumer with the specified username, creates it when it does not exist
+pub async fn admin_get_or_create_consumer(cfg: &ApisixConfig, id: &str, req: &ConsumerRequest) -> Result<TypedItem<ApisixConsumer>> {
+    client_admin_impl::api_admin_get_or_create_consumer(cfg, id, req).await
+}
+
+/// Renames a Consumer: copies its configuration to `new_id` and removes the old Consumer
+pub async fn admin_rename_consumer(cfg: &ApisixConfig, id: &str, new_id: &str) -> Result<TypedItem<ApisixConsumer>> {
+    client_admin_impl::api_admin_rename_consumer(cfg, id, new_id).await
+}
+
 /// Removes the Consumer with the specified username
 pub async fn admin_delete_consumer(cfg: &ApisixConfig, username: &str) -> Result<()> {
     client_admin_impl::api_admin_delete_consumer(cfg, username).await
 }
+
+/// Fetches the Credentials of the specified Consumer
+pub async fn admin_get_consumer_credentials(cfg: &ApisixConfig, username: &str) -> Result<ListResponse<TypedItem<ApisixCredential>>> {
+    client_admin_impl::api_admin_get_consumer_credentials(cfg, username).await
+}
+
+/// Fetches the specified Credential of a Consumer
+pub async fn admin_get_consumer_credential(cfg: &ApisixConfig, username: &str, id: &str) -> Result<TypedItem<ApisixCredential>> {
+    client_admin_impl::api_admin_get_consumer_credential(cfg, username, id).await
+}
+
+/// Creates or updates a Credential of a Consumer with the specified id
+pub async fn admin_create_consumer_credential_with_id(cfg: &ApisixConfig, username: &str, id: &str, req: &CredentialRequest) -> Result<TypedItem<ApisixCredential>> {
+    client_admin_impl::api_admin_create_consumer_credential_with_id(cfg, username, id, req).await
+}
+
+/// Removes the specified Credential of a Consumer
+pub async fn admin_delete_consumer_credential(cfg: &ApisixConfig, username: &str, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_consumer_credential(cfg, username, id).await
+}
+
+/// Fetches a list of all SSL certificates
+pub async fn admin_get_ssls(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixSsl>>> {
+    client_admin_impl::api_admin_get_ssls(cfg).await
+}
+
+/// Fetches specified SSL certificate by id
+pub async fn admin_get_ssl(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixSsl>> {
+    client_admin_impl::api_admin_get_ssl(cfg, id).await
+}
+
+/// Creates or updates an SSL certificate with the specified id
+pub async fn admin_create_ssl_with_id(cfg: &ApisixConfig, id: &str, req: &SslRequest) -> Result<TypedItem<ApisixSsl>> {
+    client_admin_impl::api_admin_create_ssl_with_id(cfg, id, req).await
+}
+
+/// Removes the SSL certificate with the specified id
+pub async fn admin_delete_ssl(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_ssl(cfg, id).await
+}
+
+/// Fetches a list of all Global rules
+pub async fn admin_get_global_rules(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixGlobalRule>>> {
+    client_admin_impl::api_admin_get_global_rules(cfg).await
+}
+
+/// Fetches specified Global rule by id
+pub async fn admin_get_global_rule(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixGlobalRule>> {
+    client_admin_impl::api_admin_get_global_rule(cfg, id).await
+}
+
+/// Creates or updates a Global rule with the specified id
+pub async fn admin_create_global_rule_with_id(cfg: &ApisixConfig, id: &str, req: &GlobalRuleRequest) -> Result<TypedItem<ApisixGlobalRule>> {
+    client_admin_impl::api_admin_create_global_rule_with_id(cfg, id, req).await
+}
+
+/// Removes the Global rule with the specified id
+pub async fn admin_delete_global_rule(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_global_rule(cfg, id).await
+}
+
+/// Fetches a list of all Plugin configs
+pub async fn admin_get_plugin_configs(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixPluginConfig>>> {
+    client_admin_impl::api_admin_get_plugin_configs(cfg).await
+}
+
+/// Fetches specified Plugin config by id
+pub async fn admin_get_plugin_config(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixPluginConfig>> {
+    client_admin_impl::api_admin_get_plugin_config(cfg, id).await
+}
+
+/// Creates or updates a Plugin config with the specified id
+pub async fn admin_create_plugin_config_with_id(cfg: &ApisixConfig, id: &str, req: &PluginConfigRequest) -> Result<TypedItem<ApisixPluginConfig>> {
+    client_admin_impl::api_admin_create_plugin_config_with_id(cfg, id, req).await
+}
+
+/// Removes the Plugin config with the specified id
+pub async fn admin_delete_plugin_config(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_plugin_config(cfg, id).await
+}
+
+/// Fetches a list of all Stream routes
+pub async fn admin_get_stream_routes(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixStreamRoute>>> {
+    client_admin_impl::api_admin_get_stream_routes(cfg).await
+}
+
+/// Fetches specified Stream route by id
+pub async fn admin_get_stream_route(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixStreamRoute>> {
+    client_admin_impl::api_admin_get_stream_route(cfg, id).await
+}
+
+/// Creates or updates a Stream route with the specified id
+pub async fn admin_create_stream_route_with_id(cfg: &ApisixConfig, id: &str, req: &StreamRouteRequest) -> Result<TypedItem<ApisixStreamRoute>> {
+    client_admin_impl::api_admin_create_stream_route_with_id(cfg, id, req).await
+}
+
+/// Removes the Stream route with the specified id
+pub async fn admin_delete_stream_route(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_stream_route(cfg, id).await
+}
+
+/// Fetches a list of all Vault secret managers
+pub async fn admin_get_vault_secrets(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixVaultSecret>>> {
+    client_admin_impl::api_admin_get_vault_secrets(cfg).await
+}
+
+/// Fetches specified Vault secret manager by id
+pub async fn admin_get_vault_secret(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixVaultSecret>> {
+    client_admin_impl::api_admin_get_vault_secret(cfg, id).await
+}
+
+/// Creates or updates a Vault secret manager with the specified id
+pub async fn admin_create_vault_secret_with_id(cfg: &ApisixConfig, id: &str, req: &VaultSecretRequest) -> Result<TypedItem<ApisixVaultSecret>> {
+    client_admin_impl::api_admin_create_vault_secret_with_id(cfg, id, req).await
+}
+
+/// Removes the Vault secret manager with the specified id
+pub async fn admin_delete_vault_secret(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_vault_secret(cfg, id).await
+}
+
+/// Fetches a list of all AWS secret managers
+pub async fn admin_get_aws_secrets(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixAwsSecret>>> {
+    client_admin_impl::api_admin_get_aws_secrets(cfg).await
+}
+
+/// Fetches specified AWS secret manager by id
+pub async fn admin_get_aws_secret(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixAwsSecret>> {
+    client_admin_impl::api_admin_get_aws_secret(cfg, id).await
+}
+
+/// Creates or updates a AWS secret manager with the specified id
+pub async fn admin_create_aws_secret_with_id(cfg: &ApisixConfig, id: &str, req: &AwsSecretRequest) -> Result<TypedItem<ApisixAwsSecret>> {
+    client_admin_impl::api_admin_create_aws_secret_with_id(cfg, id, req).await
+}
+
+/// Removes the AWS secret manager with the specified id
+pub async fn admin_delete_aws_secret(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_aws_secret(cfg, id).await
+}
+
+/// Fetches the metadata of a logger plugin (http-logger, kafka-logger, ...)
+pub async fn admin_get_logger_metadata(cfg: &ApisixConfig, plugin: &LoggerPlugin) -> Result<TypedItem<ApisixLoggerMetadata>> {
+    client_admin_impl::api_admin_get_logger_metadata(cfg, plugin).await
+}
+
+/// Creates or updates the metadata of a logger plugin, e.g. its `log_format`
+pub async fn admin_create_logger_metadata(cfg: &ApisixConfig, plugin: &LoggerPlugin, req: &LoggerMetadataRequest) -> Result<TypedItem<ApisixLoggerMetadata>> {
+    client_admin_impl::api_admin_create_logger_metadata(cfg, plugin, req).await
+}
+
+/// Fetches the metadata of the specified plugin
+pub async fn admin_get_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str) -> Result<TypedItem<Value>> {
+    client_admin_impl::api_admin_get_plugin_metadata(cfg, plugin_name).await
+}
+
+/// Creates or updates the metadata of the specified plugin
+pub async fn admin_create_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str, metadata: &Value) -> Result<TypedItem<Value>> {
+    client_admin_impl::api_admin_create_plugin_metadata(cfg, plugin_name, metadata).await
+}
+
+/// Removes the metadata of the specified plugin
+pub async fn admin_delete_plugin_metadata(cfg: &ApisixConfig, plugin_name: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_plugin_metadata(cfg, plugin_name).await
+}
+
+/// Fetches a list of all Protos
+pub async fn admin_get_protos(cfg: &ApisixConfig) -> Result<ListResponse<TypedItem<ApisixProto>>> {
+    client_admin_impl::api_admin_get_protos(cfg).await
+}
+
+/// Fetches specified Proto by id
+pub async fn admin_get_proto(cfg: &ApisixConfig, id: &str) -> Result<TypedItem<ApisixProto>> {
+    client_admin_impl::api_admin_get_proto(cfg, id).await
+}
+
+/// Creates or updates a Proto with the specified id
+pub async fn admin_create_proto_with_id(cfg: &ApisixConfig, id: &str, req: &ProtoRequest) -> Result<TypedItem<ApisixProto>> {
+    client_admin_impl::api_admin_create_proto_with_id(cfg, id, req).await
+}
+
+/// Removes the Proto with the specified id
+pub async fn admin_delete_proto(cfg: &ApisixConfig, id: &str) -> Result<()> {
+    client_admin_impl::api_admin_delete_proto(cfg, id).await
+}
+
+/// Uploads `.proto` files or compiled descriptor sets, each file stem being used as id
+/// Returns the ids to use as `proto_id` of the grpc-transcode plugin
+pub async fn admin_upload_protos(cfg: &ApisixConfig, paths: &[PathBuf]) -> Result<Vec<String>> {
+    client_admin_impl::api_admin_upload_protos(cfg, paths).await
+}
+
+/// Partially updates the Upstream with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_upstream(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixUpstream>> {
+    client_admin_impl::api_admin_patch_upstream(cfg, id, doc).await
+}
+
+/// Partially updates the Service with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_service(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixService>> {
+    client_admin_impl::api_admin_patch_service(cfg, id, doc).await
+}
+
+/// Partially updates the Route with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_route(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixRoute>> {
+    client_admin_impl::api_admin_patch_route(cfg, id, doc).await
+}
+
+/// Partially updates the Consumer group with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_consumer_group(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixConsumerGroup>> {
+    client_admin_impl::api_admin_patch_consumer_group(cfg, id, doc).await
+}
+
+/// Partially updates the SSL certificate with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_ssl(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixSsl>> {
+    client_admin_impl::api_admin_patch_ssl(cfg, id, doc).await
+}
+
+/// Partially updates the Global rule with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_global_rule(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixGlobalRule>> {
+    client_admin_impl::api_admin_patch_global_rule(cfg, id, doc).await
+}
+
+/// Partially updates the Plugin config with the specified id, `doc` is merged into the stored object
+pub async fn admin_patch_plugin_config(cfg: &ApisixConfig, id: &str, doc: &Value) -> Result<TypedItem<ApisixPluginConfig>> {
+    client_admin_impl::api_admin_patch_plugin_config(cfg, id, doc).await
+}
+
+/// Replaces a single attribute of the Route with the specified id, e.g. `plugins` or `status`
+pub async fn admin_patch_route_sub_path(cfg: &ApisixConfig, id: &str, sub_path: &str, value: &Value) -> Result<TypedItem<ApisixRoute>> {
+    client_admin_impl::api_admin_patch_route_sub_path(cfg, id, sub_path, value).await
+}
+
+/// Fetches a page of Upstreams matching the query
+pub async fn admin_get_upstreams_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixUpstream>>> {
+    client_admin_impl::api_admin_get_upstreams_with_query(cfg, query).await
+}
+
+/// Fetches a page of Services matching the query
+pub async fn admin_get_services_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixService>>> {
+    client_admin_impl::api_admin_get_services_with_query(cfg, query).await
+}
+
+/// Fetches a page of Routes matching the query
+pub async fn admin_get_routes_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixRoute>>> {
+    client_admin_impl::api_admin_get_routes_with_query(cfg, query).await
+}
+
+/// Fetches a page of Consumer groups matching the query
+pub async fn admin_get_consumer_groups_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixConsumerGroup>>> {
+    client_admin_impl::api_admin_get_consumer_groups_with_query(cfg, query).await
+}
+
+/// Fetches a page of Consumers matching the query
+pub async fn admin_get_consumers_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixConsumer>>> {
+    client_admin_impl::api_admin_get_consumers_with_query(cfg, query).await
+}
+
+/// Fetches a page of SSL certificates matching the query
+pub async fn admin_get_ssls_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixSsl>>> {
+    client_admin_impl::api_admin_get_ssls_with_query(cfg, query).await
+}
+
+/// Fetches a page of Global rules matching the query
+pub async fn admin_get_global_rules_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixGlobalRule>>> {
+    client_admin_impl::api_admin_get_global_rules_with_query(cfg, query).await
+}
+
+/// Fetches a page of Plugin configs matching the query
+pub async fn admin_get_plugin_configs_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixPluginConfig>>> {
+    client_admin_impl::api_admin_get_plugin_configs_with_query(cfg, query).await
+}
+
+/// Fetches a page of Stream routes matching the query
+pub async fn admin_get_stream_routes_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixStreamRoute>>> {
+    client_admin_impl::api_admin_get_stream_routes_with_query(cfg, query).await
+}
+
+/// Fetches a page of Protos matching the query
+pub async fn admin_get_protos_with_query(cfg: &ApisixConfig, query: &ListQuery) -> Result<ListResponse<TypedItem<ApisixProto>>> {
+    client_admin_impl::api_admin_get_protos_with_query(cfg, query).await
+}
+
+/// Fetches the Upstreams whose labels match the selector, e.g. `env=prod,team=payments`
+pub async fn admin_get_upstreams_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixUpstream>>> {
+    client_admin_impl::api_admin_get_upstreams_selected(cfg, selector).await
+}
+
+/// Fetches the Services whose labels match the selector, e.g. `env=prod,team=payments`
+pub async fn admin_get_services_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixService>>> {
+    client_admin_impl::api_admin_get_services_selected(cfg, selector).await
+}
+
+/// Fetches the Routes whose labels match the selector, e.g. `env=prod,team=payments`
+pub async fn admin_get_routes_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixRoute>>> {
+    client_admin_impl::api_admin_get_routes_selected(cfg, selector).await
+}
+
+/// Fetches the Consumer groups whose labels match the selector, e.g. `env=prod,team=payments`
+pub async fn admin_get_consumer_groups_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixConsumerGroup>>> {
+    client_admin_impl::api_admin_get_consumer_groups_selected(cfg, selector).await
+}
+
+/// Fetches the Consumers whose labels match the selector, e.g. `env=prod,team=payments`
+pub async fn admin_get_consumers_selected(cfg: &ApisixConfig, selector: &LabelSelector) -> Result<Vec<TypedItem<ApisixConsumer>>> {
+    client_admin_impl::api_admin_get_consumers_selected(cfg, selector).await
+}
+
+/// Creates or replaces mixed objects, at most `concurrency` requests are in flight
+/// The report holds the outcome of every object, in the order of the input
+pub async fn admin_bulk_apply(cfg: &ApisixConfig, objects: &[AdminObject], concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_bulk_apply(cfg, objects, concurrency).await
+}
+
+/// Removes the specified objects, a missing object counts as success
+pub async fn admin_bulk_delete(cfg: &ApisixConfig, targets: &[(ResourceKind, String)], concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_bulk_delete(cfg, targets, concurrency).await
+}
+
+/// Fetches all the objects of the specified kinds
+/// Fails on objects holding plugins or attributes that are not modelled by this crate, see [admin_backup]
+pub async fn admin_bulk_export(cfg: &ApisixConfig, kinds: &[ResourceKind], concurrency: usize) -> Result<Vec<AdminObject>> {
+    client_admin_impl::api_admin_bulk_export(cfg, kinds, concurrency).await
+}
+
+/// Removes the objects of the kind whose labels match the selector
+pub async fn admin_delete_selected(cfg: &ApisixConfig, kind: ResourceKind, selector: &LabelSelector, concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_delete_selected(cfg, kind, selector, concurrency).await
+}
+
+/// Removes the objects of the kind whose id starts with the prefix, e.g. `gen-`
+pub async fn admin_delete_by_id_prefix(cfg: &ApisixConfig, kind: ResourceKind, prefix: &str, concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_delete_by_id_prefix(cfg, kind, prefix, concurrency).await
+}
+
+/// Creates or replaces mixed objects in dependency order: Upstreams, Services, Plugin configs, Routes, ...
+/// Objects referencing an object that failed are skipped and reported as failed
+pub async fn admin_apply_ordered(cfg: &ApisixConfig, objects: &[AdminObject], concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_apply_ordered(cfg, objects, concurrency).await
+}
+
+/// Removes the specified objects in reverse dependency order
+pub async fn admin_delete_ordered(cfg: &ApisixConfig, targets: &[(ResourceKind, String)], concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_delete_ordered(cfg, targets, concurrency).await
+}
+
+/// Removes the object after the Routes and Stream Routes using it, fails when other objects still reference it
+pub async fn admin_delete_cascade(cfg: &ApisixConfig, kind: ResourceKind, id: &str, concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_delete_cascade(cfg, kind, id, concurrency).await
+}
+
+/// Fetches all the objects as a standalone configuration, write it with [StandaloneConfig::save]
+pub async fn admin_export_standalone(cfg: &ApisixConfig, concurrency: usize) -> Result<StandaloneConfig> {
+    client_admin_impl::api_admin_export_standalone(cfg, concurrency).await
+}
+
+/// Computes the create, update and delete actions needed to reach the desired state
+pub async fn admin_plan(cfg: &ApisixConfig, desired: &DesiredState) -> Result<Plan> {
+    client_admin_impl::api_admin_plan(cfg, desired).await
+}
+
+/// Executes a plan, see [ApplyOptions] for dry run, pruning and rollback
+pub async fn admin_apply(cfg: &ApisixConfig, plan: &Plan, opts: &ApplyOptions) -> Result<ApplyReport> {
+    client_admin_impl::api_admin_apply(cfg, plan, opts).await
+}
+
+/// Captures the raw objects of every resource kind, to be saved and compared later
+pub async fn admin_snapshot(cfg: &ApisixConfig) -> Result<Snapshot> {
+    client_admin_impl::api_admin_snapshot(cfg).await
+}
+
+/// Reports the objects added, removed or modified on the gateway since the snapshot
+pub async fn admin_drift_from_snapshot(cfg: &ApisixConfig, snapshot: &Snapshot) -> Result<DriftReport> {
+    client_admin_impl::api_admin_drift_from_snapshot(cfg, snapshot).await
+}
+
+/// Reports how the gateway differs from the desired state
+pub async fn admin_drift_from_desired(cfg: &ApisixConfig, desired: &DesiredState) -> Result<DriftReport> {
+    client_admin_impl::api_admin_drift_from_desired(cfg, desired).await
+}
+
+/// Exports every object of every resource kind into an archive, save it with [BackupArchive::save]
+pub async fn admin_backup(cfg: &ApisixConfig, concurrency: usize) -> Result<BackupArchive> {
+    client_admin_impl::api_admin_backup(cfg, concurrency).await
+}
+
+/// Recreates the objects of an archive in dependency order, e.g. on an empty or a staging cluster
+pub async fn admin_restore(cfg: &ApisixConfig, archive: &BackupArchive, concurrency: usize) -> Result<BulkReport> {
+    client_admin_impl::api_admin_restore(cfg, archive, concurrency).await
+}
+
 // region: controller
 /// Returns the JSON schema used by the APISIX instance (untyped JSON)
 pub async  fn ctrl_schema(cfg: &ApisixConfig) -> Result<Value> {
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::models::{generate_identifier, ApisixCredential, KeyAuthBuilder, Plugins};
+use crate::{Result};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredentialBuilder {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub plugins: Option<Plugins>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl CredentialBuilder {
+    pub fn new() -> Self {
+        CredentialRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Description of the Credential
+    pub fn with_desc(mut self, desc: String) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    /// Auth plugins of the Credential (key-auth, basic-auth, ...). See [Plugins] for more
+    pub fn with_plugins(mut self, plugins: Plugins) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
+
+    /// Sets a key-auth plugin holding the specified key
+    pub fn with_key_auth(mut self, key: impl Into<String>) -> Result<Self> {
+        let key_auth = KeyAuthBuilder::new().with_key(key).build()?;
+        let plugins = self.plugins.take().unwrap_or_default();
+        self.plugins = Some(Plugins { key_auth: Some(key_auth), ..plugins });
+        Ok(self)
+    }
+
+    /// Attributes of the Credential specified as key-value pairs
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    pub fn build(self) -> Result<CredentialRequest> {
+        Ok(CredentialRequest {
+            id: self.id,
+            desc: self.desc,
+            plugins: self.plugins,
+            labels: self.labels,
+        })
+    }
+}
+
+/// A Credential holds the authentication configuration of a Consumer (Apisix 3.7+)
+/// A Consumer can have several Credentials, e.g. to keep two keys active during a key rotation
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CredentialRequest {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub plugins: Option<Plugins>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl Default for CredentialRequest {
+    fn default() -> Self {
+        CredentialRequest {
+            id: Some(generate_identifier()),
+            desc: None,
+            plugins: None,
+            labels: None,
+        }
+    }
+}
+
+impl From<CredentialRequest> for CredentialBuilder {
+    fn from(credential: CredentialRequest) -> Self {
+        CredentialBuilder {
+            id: credential.id,
+            desc: credential.desc,
+            plugins: credential.plugins,
+            labels: credential.labels,
+        }
+    }
+}
+
+impl From<ApisixCredential> for CredentialRequest {
+    fn from(credential: ApisixCredential) -> Self {
+        CredentialRequest {
+            id: credential.id.map(|id| id.as_str().map(str::to_string).unwrap_or_else(|| id.to_string())),
+            desc: credential.desc,
+            plugins: credential.plugins,
+            labels: credential.labels,
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_credential_with_key_auth() {
+        let req = CredentialBuilder::new()
+            .with_id("key-2024".to_string())
+            .with_key_auth("secret").unwrap()
+            .build().unwrap();
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["plugins"]["key-auth"]["key"], "secret");
+        assert_eq!(json["id"], "key-2024");
+    }
+}
+// endregion: tests
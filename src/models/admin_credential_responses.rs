@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::models::Plugins;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixCredential {
+    pub id: Option<Value>, //preferred String
+    pub desc: Option<String>,
+    pub plugins: Option<Plugins>,
+    pub labels: Option<HashMap<String, String>>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_credential_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 412,
+            "key": "/apisix/consumers/jack/credentials/key-2024",
+            "value": {
+                "id": "key-2024",
+                "desc": "rotated in 2024",
+                "plugins": {
+                    "key-auth": {
+                        "key": "auth-one"
+                    }
+                },
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 412
+        }"#;
+        let nodes: TypedItem<ApisixCredential> = serde_json::from_str(nodes).unwrap();
+        let credential = nodes.value.unwrap();
+        assert_eq!(credential.id.unwrap(), "key-2024");
+        assert_eq!(credential.plugins.unwrap().key_auth.unwrap().key.unwrap(), "auth-one");
+    }
+}
+// endregion: tests
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::models::{generate_identifier, Plugins};
+use crate::{Result};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlobalRuleBuilder {
+    pub id: Option<String>,
+    pub plugins: Plugins,
+}
+
+impl GlobalRuleBuilder {
+    pub fn new() -> Self {
+        GlobalRuleRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Plugins that are executed for every request, before the plugins of the Route. See [Plugins] for more
+    pub fn with_plugins(mut self, plugins: Plugins) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    pub fn build(self) -> Result<GlobalRuleRequest> {
+        Ok(GlobalRuleRequest {
+            id: self.id,
+            plugins: self.plugins,
+        })
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlobalRuleRequest {
+    pub id: Option<String>,
+    pub plugins: Plugins,
+}
+
+impl Default for GlobalRuleRequest {
+    fn default() -> Self {
+        GlobalRuleRequest {
+            id: Some(generate_identifier()),
+            plugins: Plugins::default(),
+        }
+    }
+}
+
+impl From<GlobalRuleRequest> for GlobalRuleBuilder {
+    fn from(global_rule: GlobalRuleRequest) -> Self {
+        GlobalRuleBuilder {
+            id: global_rule.id,
+            plugins: global_rule.plugins,
+        }
+    }
+}
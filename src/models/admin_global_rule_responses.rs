@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::models::Plugins;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixGlobalRule {
+    pub id: Option<Value>,
+    pub plugins: Option<Plugins>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_global_rule_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 412,
+            "key": "/apisix/global_rules/1",
+            "value": {
+                "id": "1",
+                "plugins": {
+                    "limit-count": {
+                        "count": 1000,
+                        "time_window": 60,
+                        "key": "remote_addr",
+                        "key_type": "var"
+                    }
+                },
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 412
+        }"#;
+        let nodes: TypedItem<ApisixGlobalRule> = serde_json::from_str(nodes).unwrap();
+        assert_eq!(nodes.key.unwrap(), "/apisix/global_rules/1");
+        assert_eq!(nodes.value.clone().unwrap().id.unwrap(), "1");
+        assert_eq!(nodes.value.clone().unwrap().plugins.unwrap().limit_count.unwrap().count.unwrap(), 1000);
+    }
+}
+// endregion: tests
@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumString};
+use crate::error::ApisixClientError;
+use crate::models::desired_state::diff_values;
+use crate::models::{ConsumerGroupRequest, ConsumerRequest, GlobalRuleRequest, PluginConfigRequest, ProtoRequest, RouteRequest, ServiceRequest, SslRequest, StreamRouteRequest, UpstreamRequest};
+use crate::{Result};
+
+/// Collections of the Admin API, named after their path (`/apisix/admin/{kind}`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[strum(ascii_case_insensitive)]
+#[non_exhaustive]
+pub enum ResourceKind {
+    upstreams,
+    services,
+    routes,
+    consumer_groups,
+    consumers,
+    ssls,
+    global_rules,
+    plugin_configs,
+    stream_routes,
+    protos,
+    secrets,
+    plugin_metadata,
+    /// Credentials of the Consumers, listed per Consumer and identified as `{username}/credentials/{id}`
+    credentials,
+}
+
+impl ResourceKind {
+    /// All the kinds, exported by [crate::client::AdminConnector::backup]
+    pub const ALL: [ResourceKind; 13] = [
+        ResourceKind::upstreams,
+        ResourceKind::services,
+        ResourceKind::routes,
+        ResourceKind::consumer_groups,
+        ResourceKind::consumers,
+        ResourceKind::ssls,
+        ResourceKind::global_rules,
+        ResourceKind::plugin_configs,
+        ResourceKind::stream_routes,
+        ResourceKind::protos,
+        ResourceKind::secrets,
+        ResourceKind::plugin_metadata,
+        ResourceKind::credentials,
+    ];
+
+    /// Kinds modelled by an [AdminObject], handled by the bulk operations
+    pub const TYPED: [ResourceKind; 10] = [
+        ResourceKind::upstreams,
+        ResourceKind::services,
+        ResourceKind::routes,
+        ResourceKind::consumer_groups,
+        ResourceKind::consumers,
+        ResourceKind::ssls,
+        ResourceKind::global_rules,
+        ResourceKind::plugin_configs,
+        ResourceKind::stream_routes,
+        ResourceKind::protos,
+    ];
+
+    /// Order in which the kinds are created: a kind only references kinds created before it
+    /// Deletion happens in the reverse order
+    ///
+    /// Secrets come first as any object may hold `$secret://` references, Credentials follow their Consumer.
+    pub const APPLY_ORDER: [ResourceKind; 13] = [
+        ResourceKind::secrets,
+        ResourceKind::plugin_metadata,
+        ResourceKind::ssls,
+        ResourceKind::protos,
+        ResourceKind::upstreams,
+        ResourceKind::services,
+        ResourceKind::plugin_configs,
+        ResourceKind::global_rules,
+        ResourceKind::consumer_groups,
+        ResourceKind::consumers,
+        ResourceKind::credentials,
+        ResourceKind::routes,
+        ResourceKind::stream_routes,
+    ];
+
+    /// Position of the kind in [ResourceKind::APPLY_ORDER]
+    pub fn apply_rank(&self) -> usize {
+        ResourceKind::APPLY_ORDER.iter().position(|k| k == self).unwrap_or(ResourceKind::APPLY_ORDER.len())
+    }
+
+    /// References between kinds: (referencing kind, JSON pointer of the reference, referenced kind)
+    pub const REFERENCES: [(ResourceKind, &'static str, ResourceKind); 10] = [
+        (ResourceKind::services, "/upstream_id", ResourceKind::upstreams),
+        (ResourceKind::routes, "/upstream_id", ResourceKind::upstreams),
+        (ResourceKind::routes, "/service_id", ResourceKind::services),
+        (ResourceKind::routes, "/plugin_config_id", ResourceKind::plugin_configs),
+        (ResourceKind::routes, "/plugins/grpc-transcode/proto_id", ResourceKind::protos),
+        (ResourceKind::stream_routes, "/upstream_id", ResourceKind::upstreams),
+        (ResourceKind::stream_routes, "/service_id", ResourceKind::services),
+        (ResourceKind::consumers, "/group_id", ResourceKind::consumer_groups),
+        (ResourceKind::services, "/plugins/grpc-transcode/proto_id", ResourceKind::protos),
+        (ResourceKind::plugin_configs, "/plugins/grpc-transcode/proto_id", ResourceKind::protos),
+    ];
+
+    /// Kinds that can reference this kind, with the JSON pointer of the reference
+    pub fn referenced_by(&self) -> Vec<(ResourceKind, &'static str)> {
+        ResourceKind::REFERENCES.iter()
+            .filter(|(_, _, target)| target == self)
+            .map(|(kind, pointer, _)| (*kind, *pointer))
+            .collect()
+    }
+
+    /// Name of the attribute holding the id, Consumers are keyed by `username`
+    pub fn id_field(&self) -> &'static str {
+        match self {
+            ResourceKind::consumers => "username",
+            _ => "id",
+        }
+    }
+
+    /// Id of an object from its key, relative to the collection of the kind
+    ///
+    /// e.g. `/apisix/routes/r1` gives `r1`, `/apisix/secrets/vault/1` gives `vault/1`
+    /// and `/apisix/consumers/jack/credentials/c1` gives `jack/credentials/c1`
+    pub fn id_from_key(&self, key: &str) -> Option<String> {
+        let collection = match self {
+            ResourceKind::credentials => ResourceKind::consumers.to_string(),
+            _ => self.to_string(),
+        };
+        key.split_once(&format!("/{}/", collection))
+            .map(|(_, id)| id.to_string())
+            .filter(|id| !id.is_empty())
+    }
+}
+
+/// Any object of the Admin API, used to handle mixed resources in bulk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum AdminObject {
+    Upstream(UpstreamRequest),
+    Service(ServiceRequest),
+    Route(RouteRequest),
+    ConsumerGroup(ConsumerGroupRequest),
+    Consumer(ConsumerRequest),
+    Ssl(SslRequest),
+    GlobalRule(GlobalRuleRequest),
+    PluginConfig(PluginConfigRequest),
+    StreamRoute(StreamRouteRequest),
+    Proto(ProtoRequest),
+}
+
+macro_rules! impl_admin_object_from {
+    ($($variant:ident($req:ty)),*) => {
+        $(impl From<$req> for AdminObject {
+            fn from(req: $req) -> Self {
+                AdminObject::$variant(req)
+            }
+        })*
+    };
+}
+
+impl_admin_object_from!(Upstream(UpstreamRequest), Service(ServiceRequest), Route(RouteRequest),
+    ConsumerGroup(ConsumerGroupRequest), Consumer(ConsumerRequest), Ssl(SslRequest), GlobalRule(GlobalRuleRequest),
+    PluginConfig(PluginConfigRequest), StreamRoute(StreamRouteRequest), Proto(ProtoRequest));
+
+impl AdminObject {
+    pub fn kind(&self) -> ResourceKind {
+        match self {
+            AdminObject::Upstream(_) => ResourceKind::upstreams,
+            AdminObject::Service(_) => ResourceKind::services,
+            AdminObject::Route(_) => ResourceKind::routes,
+            AdminObject::ConsumerGroup(_) => ResourceKind::consumer_groups,
+            AdminObject::Consumer(_) => ResourceKind::consumers,
+            AdminObject::Ssl(_) => ResourceKind::ssls,
+            AdminObject::GlobalRule(_) => ResourceKind::global_rules,
+            AdminObject::PluginConfig(_) => ResourceKind::plugin_configs,
+            AdminObject::StreamRoute(_) => ResourceKind::stream_routes,
+            AdminObject::Proto(_) => ResourceKind::protos,
+        }
+    }
+
+    /// Id of the object, the username for a Consumer
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            AdminObject::Upstream(r) => r.id.as_deref(),
+            AdminObject::Service(r) => r.id.as_deref(),
+            AdminObject::Route(r) => r.id.as_deref(),
+            AdminObject::ConsumerGroup(r) => r.id.as_deref(),
+            AdminObject::Consumer(r) => r.username.as_deref(),
+            AdminObject::Ssl(r) => r.id.as_deref(),
+            AdminObject::GlobalRule(r) => r.id.as_deref(),
+            AdminObject::PluginConfig(r) => r.id.as_deref(),
+            AdminObject::StreamRoute(r) => r.id.as_deref(),
+            AdminObject::Proto(r) => r.id.as_deref(),
+        }
+    }
+
+    /// Labels of the object, Global rules and Stream routes have no labels
+    pub fn labels(&self) -> Option<&HashMap<String, String>> {
+        match self {
+            AdminObject::Upstream(r) => r.labels.as_ref(),
+            AdminObject::Service(r) => r.labels.as_ref(),
+            AdminObject::Route(r) => r.labels.as_ref(),
+            AdminObject::ConsumerGroup(r) => r.labels.as_ref(),
+            AdminObject::Consumer(r) => r.labels.as_ref(),
+            AdminObject::Ssl(r) => r.labels.as_ref(),
+            AdminObject::PluginConfig(r) => r.labels.as_ref(),
+            AdminObject::Proto(r) => r.labels.as_ref(),
+            AdminObject::GlobalRule(_) | AdminObject::StreamRoute(_) => None,
+        }
+    }
+
+    /// Objects referenced by this object, e.g. the upstream and service of a Route
+    pub fn references(&self) -> Vec<(ResourceKind, String)> {
+        let kind = self.kind();
+        let Ok(value) = self.to_value() else {
+            return vec![];
+        };
+        ResourceKind::REFERENCES.iter()
+            .filter(|(referrer, _, _)| *referrer == kind)
+            .filter_map(|(_, pointer, target)| value.pointer(pointer)
+                .and_then(Value::as_str)
+                .map(|id| (*target, id.to_string())))
+            .collect()
+    }
+
+    /// JSON body sent to the Admin API
+    pub fn to_value(&self) -> Result<Value> {
+        let value = match self {
+            AdminObject::Upstream(r) => serde_json::to_value(r),
+            AdminObject::Service(r) => serde_json::to_value(r),
+            AdminObject::Route(r) => serde_json::to_value(r),
+            AdminObject::ConsumerGroup(r) => serde_json::to_value(r),
+            AdminObject::Consumer(r) => serde_json::to_value(r),
+            AdminObject::Ssl(r) => serde_json::to_value(r),
+            AdminObject::GlobalRule(r) => serde_json::to_value(r),
+            AdminObject::PluginConfig(r) => serde_json::to_value(r),
+            AdminObject::StreamRoute(r) => serde_json::to_value(r),
+            AdminObject::Proto(r) => serde_json::to_value(r),
+        };
+        value.map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+
+    /// Reads an object as returned by the Admin API
+    ///
+    /// Numeric ids are turned into strings, the [crate::models::SERVER_MANAGED_FIELDS] and the attributes
+    /// holding their Apisix default (see [crate::models::SERVER_DEFAULTS]) are dropped. Plugins and attributes that are not
+    /// modelled by this crate are rejected, with the list of their paths, rather than lost.
+    pub fn from_value(kind: ResourceKind, value: Value) -> Result<Self> {
+        let raw = value.clone();
+        let object = Self::from_value_lossy(kind, value)?;
+        let mut diffs = vec![];
+        diff_values("", &object.to_value()?, &raw, Some(kind), &mut diffs);
+        let lost: Vec<String> = diffs.into_iter()
+            .filter(|d| d.live.as_ref().is_some_and(|v| !v.is_null()))
+            .map(|d| d.path)
+            .collect();
+        if !lost.is_empty() {
+            return Err(ApisixClientError::InvalidRequest(format!("{} {} has attributes not supported by this crate: {}",
+                kind, object.id().unwrap_or_default(), lost.join(", "))));
+        }
+        Ok(object)
+    }
+
+    fn from_value_lossy(kind: ResourceKind, mut value: Value) -> Result<Self> {
+        if let Some(object) = value.as_object_mut() {
+            for field in ["id", "upstream_id", "service_id", "plugin_config_id", "group_id"] {
+                if let Some(Value::Number(n)) = object.get(field) {
+                    let id = n.to_string();
+                    object.insert(field.to_string(), Value::String(id));
+                }
+            }
+            if matches!(kind, ResourceKind::services | ResourceKind::consumer_groups | ResourceKind::global_rules) {
+                object.entry("plugins").or_insert_with(|| Value::Object(Default::default()));
+            }
+        }
+        let invalid = |e: serde_json::Error| ApisixClientError::InvalidRequest(format!("invalid {} object: {}", kind, e));
+        Ok(match kind {
+            ResourceKind::upstreams => AdminObject::Upstream(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::services => AdminObject::Service(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::routes => AdminObject::Route(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::consumer_groups => AdminObject::ConsumerGroup(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::consumers => AdminObject::Consumer(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::ssls => AdminObject::Ssl(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::global_rules => AdminObject::GlobalRule(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::plugin_configs => AdminObject::PluginConfig(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::stream_routes => AdminObject::StreamRoute(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::protos => AdminObject::Proto(serde_json::from_value(value).map_err(invalid)?),
+            ResourceKind::secrets | ResourceKind::plugin_metadata | ResourceKind::credentials => {
+                return Err(ApisixClientError::InvalidRequest(format!("{} objects are not modelled by AdminObject", kind)));
+            },
+        })
+    }
+}
+
+/// Sorts the objects in dependency order for creation, see [ResourceKind::APPLY_ORDER]
+/// The order of the objects of the same kind is kept
+pub fn sort_for_apply(objects: &mut [AdminObject]) {
+    objects.sort_by_key(|o| o.kind().apply_rank());
+}
+
+/// Sorts the targets in dependency order for deletion: referencing objects first
+pub fn sort_for_delete(targets: &mut [(ResourceKind, String)]) {
+    targets.sort_by_key(|(kind, _)| std::cmp::Reverse(kind.apply_rank()));
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::RouteBuilder;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_admin_object_from_value() {
+        let value = serde_json::json!({
+            "id": 1,
+            "uri": "/payments/*",
+            "upstream_id": 2,
+            "labels": { "team": "payments" },
+            "create_time": 1727776498,
+            "update_time": 1727776498
+        });
+        let object = AdminObject::from_value(ResourceKind::routes, value).unwrap();
+        assert_eq!(object.kind(), ResourceKind::routes);
+        assert_eq!(object.id(), Some("1"));
+        assert_eq!(object.labels().unwrap()["team"], "payments");
+        let body = object.to_value().unwrap();
+        assert_eq!(body["upstream_id"], "2");
+        assert!(body.get("create_time").is_none());
+
+        let service = AdminObject::from_value(ResourceKind::services, serde_json::json!({"id": "s1"})).unwrap();
+        assert_eq!(service.id(), Some("s1"));
+
+        // defaults filled in by Apisix are not attributes of the request
+        let upstream = serde_json::json!({
+            "id": "u1", "type": "roundrobin", "scheme": "http", "pass_host": "pass", "hash_on": "vars",
+            "nodes": {"127.0.0.1:8080": 1}, "create_time": 1727776498
+        });
+        assert!(AdminObject::from_value(ResourceKind::upstreams, upstream).is_ok());
+
+        let route = serde_json::json!({
+            "id": "r1", "uri": "/", "pass_host": "node",
+            "plugins": {"cors": {"allow_origins": "*"}, "limit-count": {"count": 1, "time_window": 60}}
+        });
+        let Err(ApisixClientError::InvalidRequest(error)) = AdminObject::from_value(ResourceKind::routes, route) else {
+            panic!("unsupported attributes accepted");
+        };
+        assert!(error.contains("/pass_host") && error.contains("/plugins/cors"), "{}", error);
+        assert!(!error.contains("limit-count"), "{}", error);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_dependency_order() {
+        let route = RouteBuilder::new().with_id("r1".to_string()).with_uri("/".to_string())
+            .with_upstream_id("u1".to_string()).build().unwrap();
+        let upstream = crate::models::UpstreamBuilder::new().with_id("u1".to_string()).build().unwrap();
+        let mut objects: Vec<AdminObject> = vec![route.into(), upstream.into()];
+        sort_for_apply(&mut objects);
+        assert_eq!(objects[0].kind(), ResourceKind::upstreams);
+        assert_eq!(objects[1].references(), vec![(ResourceKind::upstreams, "u1".to_string())]);
+
+        let mut targets = vec![(ResourceKind::consumer_groups, "g1".to_string()), (ResourceKind::consumers, "jack".to_string())];
+        sort_for_delete(&mut targets);
+        assert_eq!(targets[0].0, ResourceKind::consumers);
+        assert!(ResourceKind::upstreams.referenced_by().contains(&(ResourceKind::routes, "/upstream_id")));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_admin_object_from_request() {
+        let route = RouteBuilder::new().with_id("r1".to_string()).with_uri("/".to_string()).build().unwrap();
+        let object: AdminObject = route.into();
+        assert_eq!(object.id(), Some("r1"));
+        assert_eq!("consumer_groups".parse::<ResourceKind>().unwrap(), ResourceKind::consumer_groups);
+        assert_eq!(ResourceKind::consumers.id_field(), "username");
+        assert_eq!(ResourceKind::credentials.id_from_key("/apisix/consumers/jack/credentials/c1").unwrap(), "jack/credentials/c1");
+        assert_eq!(ResourceKind::secrets.id_from_key("/apisix/secrets/vault/1").unwrap(), "vault/1");
+        assert_eq!(ResourceKind::routes.id_from_key("/apisix/routes/r1").unwrap(), "r1");
+        assert!(ResourceKind::credentials.apply_rank() > ResourceKind::consumers.apply_rank());
+    }
+}
+// endregion: tests
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::models::{generate_identifier, Plugins};
+use crate::{Result};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigBuilder {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub plugins: Plugins,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl PluginConfigBuilder {
+    pub fn new() -> Self {
+        PluginConfigRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Description of usage scenarios
+    pub fn with_desc(mut self, desc: String) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    /// Plugins shared by the Routes bound to this Plugin Config. See [Plugins] for more
+    pub fn with_plugins(mut self, plugins: Plugins) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Attributes of the Plugin Config specified as key-value pairs
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    pub fn build(self) -> Result<PluginConfigRequest> {
+        Ok(PluginConfigRequest {
+            id: self.id,
+            desc: self.desc,
+            plugins: self.plugins,
+            labels: self.labels,
+        })
+    }
+}
+
+/// A Plugin Config bundles a set of plugins, reused by Routes through `plugin_config_id`
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginConfigRequest {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub plugins: Plugins,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl Default for PluginConfigRequest {
+    fn default() -> Self {
+        PluginConfigRequest {
+            id: Some(generate_identifier()),
+            desc: None,
+            plugins: Plugins::default(),
+            labels: None,
+        }
+    }
+}
+
+impl From<PluginConfigRequest> for PluginConfigBuilder {
+    fn from(plugin_config: PluginConfigRequest) -> Self {
+        PluginConfigBuilder {
+            id: plugin_config.id,
+            desc: plugin_config.desc,
+            plugins: plugin_config.plugins,
+            labels: plugin_config.labels,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::models::Plugins;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixPluginConfig {
+    pub id: Option<Value>,
+    pub desc: Option<String>,
+    pub plugins: Option<Plugins>,
+    pub labels: Option<HashMap<String, String>>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_plugin_config_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 512,
+            "key": "/apisix/plugin_configs/auth-and-quota",
+            "value": {
+                "id": "auth-and-quota",
+                "desc": "Key auth with rate limiting",
+                "plugins": {
+                    "key-auth": {
+                        "header": "apikey"
+                    },
+                    "limit-count": {
+                        "count": 100,
+                        "time_window": 60
+                    }
+                },
+                "labels": { "team": "payments" },
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 512
+        }"#;
+        let nodes: TypedItem<ApisixPluginConfig> = serde_json::from_str(nodes).unwrap();
+        let plugin_config = nodes.value.unwrap();
+        assert_eq!(plugin_config.id.unwrap(), "auth-and-quota");
+        assert_eq!(plugin_config.plugins.clone().unwrap().key_auth.unwrap().header.unwrap(), "apikey");
+        assert_eq!(plugin_config.plugins.unwrap().limit_count.unwrap().count.unwrap(), 100);
+        assert_eq!(plugin_config.labels.unwrap()["team"], "payments");
+    }
+}
+// endregion: tests
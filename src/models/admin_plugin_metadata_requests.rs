@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+use crate::error::ApisixClientError;
+use crate::{Result};
+
+/// Logger plugins sharing the [LoggerMetadataRequest] metadata
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum LoggerPlugin {
+    #[serde(rename = "http-logger")]
+    #[strum(serialize = "http-logger")]
+    http_logger,
+    #[serde(rename = "kafka-logger")]
+    #[strum(serialize = "kafka-logger")]
+    kafka_logger,
+    #[serde(rename = "tcp-logger")]
+    #[strum(serialize = "tcp-logger")]
+    tcp_logger,
+    #[serde(rename = "udp-logger")]
+    #[strum(serialize = "udp-logger")]
+    udp_logger,
+    #[serde(rename = "file-logger")]
+    #[strum(serialize = "file-logger")]
+    file_logger,
+    #[serde(rename = "syslog")]
+    #[strum(serialize = "syslog")]
+    syslog,
+    #[serde(rename = "rocketmq-logger")]
+    #[strum(serialize = "rocketmq-logger")]
+    rocketmq_logger,
+    #[serde(rename = "clickhouse-logger")]
+    #[strum(serialize = "clickhouse-logger")]
+    clickhouse_logger,
+    #[serde(rename = "elasticsearch-logger")]
+    #[strum(serialize = "elasticsearch-logger")]
+    elasticsearch_logger,
+    #[serde(rename = "loki-logger")]
+    #[strum(serialize = "loki-logger")]
+    loki_logger,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Validate, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggerMetadataBuilder {
+    pub log_format: Option<HashMap<String, Value>>,
+    #[validate(range(min = 1))]
+    pub max_pending_entries: Option<i64>,
+}
+
+impl LoggerMetadataBuilder {
+    pub fn new() -> Self {
+        LoggerMetadataRequest::default().into()
+    }
+
+    /// Log format declared as key-value pairs, values starting with `$` reference Apisix or Nginx variables
+    /// Example: {"host": "$host", "@timestamp": "$time_iso8601", "client_ip": "$remote_addr"}
+    pub fn with_log_format(mut self, log_format: HashMap<String, Value>) -> Self {
+        self.log_format = Some(log_format);
+        self
+    }
+
+    /// Adds a single entry to the log format
+    /// Example: `with_log_field("client_ip", "$remote_addr")`
+    pub fn with_log_field(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.log_format.get_or_insert_with(HashMap::new).insert(name.into(), value.into());
+        self
+    }
+
+    /// Maximum number of pending entries in the batch processor
+    pub fn with_max_pending_entries(mut self, max_pending_entries: i64) -> Self {
+        self.max_pending_entries = Some(max_pending_entries);
+        self
+    }
+
+    pub fn build(self) -> Result<LoggerMetadataRequest> {
+        self.validate().map_err(|v| ApisixClientError::PluginConfigException(v.to_string()))?;
+        Ok(LoggerMetadataRequest {
+            log_format: self.log_format,
+            max_pending_entries: self.max_pending_entries,
+        })
+    }
+}
+
+/// Plugin metadata of the logger plugins (http-logger, kafka-logger, ...), applied to every
+/// Route or Service the logger is configured on
+/// [Documentation](https://apisix.apache.org/docs/apisix/terminology/plugin-metadata/)
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggerMetadataRequest {
+    pub log_format: Option<HashMap<String, Value>>,
+    pub max_pending_entries: Option<i64>,
+}
+
+impl From<LoggerMetadataRequest> for LoggerMetadataBuilder {
+    fn from(metadata: LoggerMetadataRequest) -> Self {
+        LoggerMetadataBuilder {
+            log_format: metadata.log_format,
+            max_pending_entries: metadata.max_pending_entries,
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_logger_metadata_build() {
+        let req = LoggerMetadataBuilder::new()
+            .with_log_field("host", "$host")
+            .with_log_field("client_ip", "$remote_addr")
+            .build().unwrap();
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["log_format"]["client_ip"], "$remote_addr");
+        assert!(json.get("max_pending_entries").is_none());
+        assert_eq!(LoggerPlugin::kafka_logger.to_string(), "kafka-logger");
+        assert!(LoggerMetadataBuilder::new().with_max_pending_entries(0).build().is_err());
+    }
+}
+// endregion: tests
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixLoggerMetadata {
+    pub id: Option<Value>, //name of the plugin
+    pub log_format: Option<HashMap<String, Value>>,
+    pub max_pending_entries: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_logger_metadata_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 710,
+            "key": "/apisix/plugin_metadata/http-logger",
+            "value": {
+                "id": "http-logger",
+                "log_format": {
+                    "host": "$host",
+                    "@timestamp": "$time_iso8601",
+                    "client_ip": "$remote_addr"
+                }
+            },
+            "modifiedIndex": 710
+        }"#;
+        let nodes: TypedItem<ApisixLoggerMetadata> = serde_json::from_str(nodes).unwrap();
+        let metadata = nodes.value.unwrap();
+        assert_eq!(metadata.id.unwrap(), "http-logger");
+        assert_eq!(metadata.log_format.unwrap()["@timestamp"], "$time_iso8601");
+    }
+}
+// endregion: tests
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::path::Path;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use crate::error::ApisixClientError;
+use crate::models::generate_identifier;
+use crate::{Result};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtoBuilder {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub content: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl ProtoBuilder {
+    pub fn new() -> Self {
+        ProtoRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Description of the proto
+    pub fn with_desc(mut self, desc: String) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    /// Content of a `.proto` file, or a base64 encoded descriptor set
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Compiled descriptor set (`protoc --include_imports --descriptor_set_out`), stored base64 encoded
+    /// Required when the proto imports other files
+    pub fn with_descriptor_set(self, descriptor_set: &[u8]) -> Self {
+        self.with_content(STANDARD.encode(descriptor_set))
+    }
+
+    /// Reads the content from a `.proto` file, any other file is read as a compiled descriptor set
+    pub fn with_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let unreadable = |e: std::io::Error| ApisixClientError::InvalidRequest(format!("unable to read {}: {}", path.display(), e));
+        if path.extension().is_some_and(|ext| ext == "proto") {
+            let content = std::fs::read_to_string(path).map_err(unreadable)?;
+            Ok(self.with_content(content))
+        } else {
+            let descriptor_set = std::fs::read(path).map_err(unreadable)?;
+            Ok(self.with_descriptor_set(&descriptor_set))
+        }
+    }
+
+    /// Attributes of the proto specified as key-value pairs
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    pub fn build(self) -> Result<ProtoRequest> {
+        if self.content.as_deref().is_none_or(str::is_empty) {
+            return Err(ApisixClientError::InvalidRequest("proto requires a content".to_string()));
+        }
+        Ok(ProtoRequest {
+            id: self.id,
+            desc: self.desc,
+            content: self.content,
+            labels: self.labels,
+        })
+    }
+}
+
+/// Protocol buffers definition used by the grpc-transcode plugin, see [crate::models::GrpcTranscodeBuilder::with_proto_id]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtoRequest {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub content: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl Default for ProtoRequest {
+    fn default() -> Self {
+        ProtoRequest {
+            id: Some(generate_identifier()),
+            desc: None,
+            content: None,
+            labels: None,
+        }
+    }
+}
+
+impl From<ProtoRequest> for ProtoBuilder {
+    fn from(proto: ProtoRequest) -> Self {
+        ProtoBuilder {
+            id: proto.id,
+            desc: proto.desc,
+            content: proto.content,
+            labels: proto.labels,
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_proto_build() {
+        assert!(ProtoBuilder::new().build().is_err());
+        let req = ProtoBuilder::new()
+            .with_id("helloworld".to_string())
+            .with_descriptor_set(b"\x0a\x10helloworld.proto")
+            .build().unwrap();
+        assert_eq!(req.content.unwrap(), "ChBoZWxsb3dvcmxkLnByb3Rv");
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_proto_from_file() {
+        let path = std::env::temp_dir().join("apisix_admin_client_test.proto");
+        std::fs::write(&path, "syntax = \"proto3\";\npackage helloworld;\n").unwrap();
+        let req = ProtoBuilder::new().with_file(&path).unwrap().build().unwrap();
+        assert!(req.content.unwrap().starts_with("syntax"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+// endregion: tests
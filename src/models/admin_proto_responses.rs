@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixProto {
+    pub id: Option<Value>, //preferred String
+    pub desc: Option<String>,
+    pub content: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_proto_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 810,
+            "key": "/apisix/protos/helloworld",
+            "value": {
+                "id": "helloworld",
+                "content": "syntax = \"proto3\";\npackage helloworld;",
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 810
+        }"#;
+        let nodes: TypedItem<ApisixProto> = serde_json::from_str(nodes).unwrap();
+        let proto = nodes.value.unwrap();
+        assert_eq!(proto.id.unwrap(), "helloworld");
+        assert!(proto.content.unwrap().starts_with("syntax"));
+    }
+}
+// endregion: tests
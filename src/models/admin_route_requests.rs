@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::models::{generate_identifier, Plugins};
 use crate::UpstreamRequest;
@@ -12,6 +13,7 @@ pub struct RouteBuilder {
     pub desc: Option<String>,
     pub status: Option<i64>,
     pub plugins: Option<Plugins>,
+    pub plugin_config_id: Option<String>,
     pub uri: Option<String>,
     pub uris: Option<Vec<String>>,
     pub host: Option<String>,
@@ -25,6 +27,7 @@ pub struct RouteBuilder {
     pub timeout: Option<ApisixTimeout>,
     pub enable_websocket: Option<bool>,
     pub priority: Option<i64>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl RouteBuilder {
@@ -62,6 +65,13 @@ impl RouteBuilder {
         self
     }
 
+    /// Id of the Plugin config bound to the Route. See [crate::models::PluginConfigRequest]
+    /// Plugins configured directly on the Route take precedence
+    pub fn with_plugin_config_id(mut self, plugin_config_id: String) -> Self {
+        self.plugin_config_id = Some(plugin_config_id);
+        self
+    }
+
     /// Matches the uri. For more advanced matching see Router
     /// Example: "/hello"
     pub fn with_uri(mut self, uri: String) -> Self {
@@ -153,6 +163,18 @@ impl RouteBuilder {
         self
     }
 
+    /// Attributes of the Route specified as key-value pairs, e.g. team and environment
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Adds a single label to the Route
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> Result<RouteRequest> {
         Ok(RouteRequest {
             id: self.id,
@@ -160,6 +182,7 @@ impl RouteBuilder {
             desc: self.desc,
             status: self.status,
             plugins: self.plugins,
+            plugin_config_id: self.plugin_config_id,
             uri: self.uri,
             uris: self.uris,
             host: self.host,
@@ -173,6 +196,7 @@ impl RouteBuilder {
             timeout: self.timeout,
             enable_websocket: self.enable_websocket,
             priority: self.priority,
+            labels: self.labels,
         })
     }
 }
@@ -185,6 +209,7 @@ pub struct RouteRequest {
     pub desc: Option<String>,
     pub status: Option<i64>,
     pub plugins: Option<Plugins>,
+    pub plugin_config_id: Option<String>,
     pub uri: Option<String>,
     pub uris: Option<Vec<String>>,
     pub host: Option<String>,
@@ -198,6 +223,7 @@ pub struct RouteRequest {
     pub timeout: Option<ApisixTimeout>,
     pub enable_websocket: Option<bool>,
     pub priority: Option<i64>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl Default for RouteRequest {
@@ -208,6 +234,7 @@ impl Default for RouteRequest {
             desc: None,
             status: None,
             plugins: None,
+            plugin_config_id: None,
             uri: None,
             uris: None,
             host: None,
@@ -221,6 +248,7 @@ impl Default for RouteRequest {
             timeout: None,
             enable_websocket: None,
             priority: None,
+            labels: None,
         }
     }
 }
@@ -233,6 +261,7 @@ impl From<RouteRequest> for RouteBuilder {
             desc: route.desc,
             status: route.status,
             plugins: route.plugins,
+            plugin_config_id: route.plugin_config_id,
             uri: route.uri,
             uris: route.uris,
             host: route.host,
@@ -246,6 +275,7 @@ impl From<RouteRequest> for RouteBuilder {
             timeout: route.timeout,
             enable_websocket: route.enable_websocket,
             priority: route.priority,
+            labels: route.labels,
         }
     }
 }
\ No newline at end of file
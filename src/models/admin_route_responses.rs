@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::models::common::ApisixTimeout;
@@ -13,6 +14,7 @@ pub struct ApisixRoute {
     pub status: Option<i64>,
     pub update_time: Option<i64>,
     pub plugins: Option<Plugins>,
+    pub plugin_config_id: Option<Value>, //preferred String
     pub uri: Option<String>,
     pub uris: Option<Vec<String>>,
     pub hosts: Option<Vec<String>>,
@@ -26,6 +28,7 @@ pub struct ApisixRoute {
     pub enable_websocket: Option<bool>,
     pub create_time: Option<i64>,
     pub priority: Option<i64>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 // region: tests
@@ -57,6 +60,7 @@ mod tests {
                     }
                 },
                 "uri": "/auth/v1/*",
+                "plugin_config_id": "auth-and-quota",
                 "upstream_id": "test_upstream",
                 "service_id": "test_service",
                 "create_time": 1727776498,
@@ -71,6 +75,7 @@ mod tests {
         assert_eq!(nodes.value.clone().unwrap().status.unwrap(), 1);
         assert_eq!(nodes.value.clone().unwrap().update_time.unwrap(), 1727776498);
         assert_eq!(nodes.value.clone().unwrap().uri.unwrap(), "/auth/v1/*");
+        assert_eq!(nodes.value.clone().unwrap().plugin_config_id.unwrap(), "auth-and-quota");
         assert_eq!(nodes.value.clone().unwrap().upstream_id.unwrap(), "test_upstream");
         assert_eq!(nodes.value.clone().unwrap().create_time.unwrap(), 1727776498);
         assert_eq!(nodes.value.clone().unwrap().priority.unwrap(), 0);
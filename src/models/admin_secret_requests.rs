@@ -0,0 +1,302 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use crate::error::ApisixClientError;
+use crate::models::generate_identifier;
+use crate::{Result};
+
+/// Secret managers supported by Apisix
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[strum(ascii_case_insensitive)]
+#[non_exhaustive]
+pub enum SecretManager {
+    vault,
+    aws,
+}
+
+// region: vault
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VaultSecretBuilder {
+    pub id: Option<String>,
+    pub uri: Option<String>,
+    pub prefix: Option<String>,
+    pub token: Option<String>,
+    pub namespace: Option<String>,
+}
+
+impl VaultSecretBuilder {
+    pub fn new() -> Self {
+        VaultSecretRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// URI of the Vault server
+    /// Example: "https://vault.example.com:8200"
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Key prefix of the secrets, e.g. the path of a kv engine
+    /// Example: "kv/apisix"
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Vault token used to read the secrets
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Vault Enterprise namespace
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn build(self) -> Result<VaultSecretRequest> {
+        if self.uri.is_none() || self.prefix.is_none() || self.token.is_none() {
+            return Err(ApisixClientError::InvalidRequest("vault secret requires uri, prefix and token".to_string()));
+        }
+        Ok(VaultSecretRequest {
+            id: self.id,
+            uri: self.uri,
+            prefix: self.prefix,
+            token: self.token,
+            namespace: self.namespace,
+        })
+    }
+}
+
+/// Configuration of a HashiCorp Vault secret manager (`/apisix/admin/secrets/vault/{id}`)
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VaultSecretRequest {
+    pub id: Option<String>,
+    pub uri: Option<String>,
+    pub prefix: Option<String>,
+    pub token: Option<String>,
+    pub namespace: Option<String>,
+}
+
+impl Default for VaultSecretRequest {
+    fn default() -> Self {
+        VaultSecretRequest {
+            id: Some(generate_identifier()),
+            uri: None,
+            prefix: None,
+            token: None,
+            namespace: None,
+        }
+    }
+}
+
+impl From<VaultSecretRequest> for VaultSecretBuilder {
+    fn from(secret: VaultSecretRequest) -> Self {
+        VaultSecretBuilder {
+            id: secret.id,
+            uri: secret.uri,
+            prefix: secret.prefix,
+            token: secret.token,
+            namespace: secret.namespace,
+        }
+    }
+}
+// endregion: vault
+
+// region: aws
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsSecretBuilder {
+    pub id: Option<String>,
+    pub access_key_id: Option<String>,
+    pub secret_access_key: Option<String>,
+    pub session_token: Option<String>,
+    pub region: Option<String>,
+    pub endpoint_url: Option<String>,
+}
+
+impl AwsSecretBuilder {
+    pub fn new() -> Self {
+        AwsSecretRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// AWS access key id
+    pub fn with_access_key_id(mut self, access_key_id: impl Into<String>) -> Self {
+        self.access_key_id = Some(access_key_id.into());
+        self
+    }
+
+    /// AWS secret access key
+    pub fn with_secret_access_key(mut self, secret_access_key: impl Into<String>) -> Self {
+        self.secret_access_key = Some(secret_access_key.into());
+        self
+    }
+
+    /// AWS session token, when using temporary credentials
+    pub fn with_session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// AWS region, defaults to us-east-1
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Endpoint of the Secrets Manager, e.g. for a VPC endpoint or LocalStack
+    pub fn with_endpoint_url(mut self, endpoint_url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(endpoint_url.into());
+        self
+    }
+
+    pub fn build(self) -> Result<AwsSecretRequest> {
+        if self.access_key_id.is_none() || self.secret_access_key.is_none() {
+            return Err(ApisixClientError::InvalidRequest("aws secret requires access_key_id and secret_access_key".to_string()));
+        }
+        Ok(AwsSecretRequest {
+            id: self.id,
+            access_key_id: self.access_key_id,
+            secret_access_key: self.secret_access_key,
+            session_token: self.session_token,
+            region: self.region,
+            endpoint_url: self.endpoint_url,
+        })
+    }
+}
+
+/// Configuration of an AWS Secrets Manager (`/apisix/admin/secrets/aws/{id}`)
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsSecretRequest {
+    pub id: Option<String>,
+    pub access_key_id: Option<String>,
+    pub secret_access_key: Option<String>,
+    pub session_token: Option<String>,
+    pub region: Option<String>,
+    pub endpoint_url: Option<String>,
+}
+
+impl Default for AwsSecretRequest {
+    fn default() -> Self {
+        AwsSecretRequest {
+            id: Some(generate_identifier()),
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+            region: None,
+            endpoint_url: None,
+        }
+    }
+}
+
+impl From<AwsSecretRequest> for AwsSecretBuilder {
+    fn from(secret: AwsSecretRequest) -> Self {
+        AwsSecretBuilder {
+            id: secret.id,
+            access_key_id: secret.access_key_id,
+            secret_access_key: secret.secret_access_key,
+            session_token: secret.session_token,
+            region: secret.region,
+            endpoint_url: secret.endpoint_url,
+        }
+    }
+}
+// endregion: aws
+
+/// Reference to a value held by a secret manager, rendered as `$secret://{manager}/{id}/{key}`
+///
+/// Apisix resolves the reference at runtime, the reference can be used in any `String` plugin field:
+/// ```
+/// use apisix_admin_client::models::plugins::LimitCountBuilder;
+/// use apisix_admin_client::models::SecretRef;
+///
+/// let limit = LimitCountBuilder::new()
+///     .with_redis_password(SecretRef::vault("1", "redis/password"))
+///     .build().unwrap();
+/// assert_eq!(limit.redis_password.unwrap(), "$secret://vault/1/redis/password");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecretRef {
+    pub manager: SecretManager,
+    pub id: String,
+    pub key: String,
+}
+
+impl SecretRef {
+    pub fn new(manager: SecretManager, id: impl Into<String>, key: impl Into<String>) -> Self {
+        SecretRef {
+            manager,
+            id: id.into(),
+            key: key.into(),
+        }
+    }
+
+    /// Reference to `key` in the Vault secret manager with the specified id
+    /// Example: `SecretRef::vault("1", "jack/auth-key")`
+    pub fn vault(id: impl Into<String>, key: impl Into<String>) -> Self {
+        SecretRef::new(SecretManager::vault, id, key)
+    }
+
+    /// Reference to `key` in the AWS secret manager with the specified id
+    pub fn aws(id: impl Into<String>, key: impl Into<String>) -> Self {
+        SecretRef::new(SecretManager::aws, id, key)
+    }
+}
+
+impl fmt::Display for SecretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "$secret://{}/{}/{}", self.manager, self.id, self.key)
+    }
+}
+
+impl From<SecretRef> for String {
+    fn from(secret: SecretRef) -> Self {
+        secret.to_string()
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::KeyAuthBuilder;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_secret_ref() {
+        let key_auth = KeyAuthBuilder::new().with_key(SecretRef::aws("prod", "consumers/jack")).build().unwrap();
+        assert_eq!(key_auth.key.unwrap(), "$secret://aws/prod/consumers/jack");
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_vault_secret_build() {
+        assert!(VaultSecretBuilder::new().with_uri("http://127.0.0.1:8200").build().is_err());
+        let req = VaultSecretBuilder::new()
+            .with_id("1".to_string())
+            .with_uri("http://127.0.0.1:8200")
+            .with_prefix("kv/apisix")
+            .with_token("root")
+            .build().unwrap();
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["prefix"], "kv/apisix");
+        assert!(json.get("namespace").is_none());
+    }
+}
+// endregion: tests
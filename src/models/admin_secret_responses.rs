@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixVaultSecret {
+    pub id: Option<Value>, //preferred String
+    pub uri: Option<String>,
+    pub prefix: Option<String>,
+    pub token: Option<String>,
+    pub namespace: Option<String>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixAwsSecret {
+    pub id: Option<Value>, //preferred String
+    pub access_key_id: Option<String>,
+    pub secret_access_key: Option<String>,
+    pub session_token: Option<String>,
+    pub region: Option<String>,
+    pub endpoint_url: Option<String>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_vault_secret_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 501,
+            "key": "/apisix/secrets/vault/1",
+            "value": {
+                "id": "vault/1",
+                "uri": "http://127.0.0.1:8200",
+                "prefix": "kv/apisix",
+                "token": "root",
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 501
+        }"#;
+        let nodes: TypedItem<ApisixVaultSecret> = serde_json::from_str(nodes).unwrap();
+        let secret = nodes.value.unwrap();
+        assert_eq!(secret.id.unwrap(), "vault/1");
+        assert_eq!(secret.prefix.unwrap(), "kv/apisix");
+    }
+}
+// endregion: tests
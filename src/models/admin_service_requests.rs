@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::UpstreamRequest;
 use crate::models::{generate_identifier, Plugins};
@@ -14,6 +15,7 @@ pub struct ServiceBuilder {
     pub upstream_id: Option<String>,
     pub plugins: Plugins,
     pub hosts: Option<Vec<String>>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl ServiceBuilder {
@@ -68,6 +70,18 @@ impl ServiceBuilder {
         self
     }
 
+    /// Attributes of the Service specified as key-value pairs, e.g. team and environment
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Adds a single label to the Service
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> Result<ServiceRequest> {
         Ok(ServiceRequest {
             id: self.id,
@@ -78,6 +92,7 @@ impl ServiceBuilder {
             upstream_id: self.upstream_id,
             plugins: self.plugins,
             hosts: self.hosts,
+            labels: self.labels,
         })
     }
 }
@@ -93,6 +108,7 @@ pub struct ServiceRequest {
     pub upstream_id: Option<String>,
     pub plugins: Plugins,
     pub hosts: Option<Vec<String>>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl Default for ServiceRequest {
@@ -106,6 +122,7 @@ impl Default for ServiceRequest {
             upstream_id: None,
             plugins: Plugins::default(),
             hosts: None,
+            labels: None,
         }
     }
 }
@@ -121,6 +138,7 @@ impl From<ServiceRequest> for ServiceBuilder {
             upstream_id: service.upstream_id,
             plugins: service.plugins,
             hosts: service.hosts,
+            labels: service.labels,
         }
     }
 }
\ No newline at end of file
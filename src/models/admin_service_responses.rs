@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::models::Plugins;
@@ -13,6 +14,7 @@ pub struct ApisixService {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub enable_websocket: Option<bool>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 // region: tests
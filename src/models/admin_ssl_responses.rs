@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::models::{SslClient, SslProtocol, SslType};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixSsl {
+    pub id: Option<Value>,
+    pub cert: Option<String>,
+    pub key: Option<String>,
+    pub certs: Option<Vec<String>>,
+    pub keys: Option<Vec<String>>,
+    pub client: Option<SslClient>,
+    pub snis: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    pub type_field: Option<SslType>,
+    pub ssl_protocols: Option<Vec<SslProtocol>>,
+    pub status: Option<i64>,
+    pub labels: Option<HashMap<String, String>>,
+    pub validity_start: Option<i64>,
+    pub validity_end: Option<i64>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_ssl_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 310,
+            "key": "/apisix/ssls/example-com",
+            "value": {
+                "id": "example-com",
+                "cert": "-----BEGIN CERTIFICATE-----",
+                "snis": ["example.com", "*.example.com"],
+                "type": "server",
+                "status": 1,
+                "ssl_protocols": ["TLSv1.2", "TLSv1.3"],
+                "client": {
+                    "ca": "-----BEGIN CERTIFICATE-----",
+                    "depth": 2
+                },
+                "labels": { "env": "prod" },
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 310
+        }"#;
+        let nodes: TypedItem<ApisixSsl> = serde_json::from_str(nodes).unwrap();
+        let ssl = nodes.value.unwrap();
+        assert_eq!(ssl.id.unwrap(), "example-com");
+        assert_eq!(ssl.snis.unwrap().len(), 2);
+        assert_eq!(ssl.type_field.unwrap(), SslType::server);
+        assert_eq!(ssl.ssl_protocols.unwrap()[0], SslProtocol::TlsV1_2);
+        assert_eq!(ssl.client.unwrap().depth.unwrap(), 2);
+        assert_eq!(ssl.labels.unwrap()["env"], "prod");
+    }
+}
+// endregion: tests
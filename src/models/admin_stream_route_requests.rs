@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use crate::models::{generate_identifier, StreamPlugins};
+use crate::UpstreamRequest;
+use crate::{Result};
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamRouteBuilder {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub remote_addr: Option<String>,
+    pub server_addr: Option<String>,
+    pub server_port: Option<i64>,
+    pub sni: Option<String>,
+    pub upstream: Option<UpstreamRequest>,
+    pub upstream_id: Option<String>,
+    pub service_id: Option<String>,
+    pub plugins: Option<StreamPlugins>,
+}
+
+impl StreamRouteBuilder {
+    pub fn new() -> Self {
+        StreamRouteRequest::default().into()
+    }
+
+    pub fn with_id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Description of usage scenarios
+    pub fn with_desc(mut self, desc: String) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    /// Filters the client by IP address. IPv4 or IPv6, CIDR notation is supported
+    /// Example: "127.0.0.1", "192.168.0.0/24"
+    pub fn with_remote_addr(mut self, remote_addr: String) -> Self {
+        self.remote_addr = Some(remote_addr);
+        self
+    }
+
+    /// Filters on the address of the APISIX server accepting the L4 connection
+    pub fn with_server_addr(mut self, server_addr: String) -> Self {
+        self.server_addr = Some(server_addr);
+        self
+    }
+
+    /// Filters on the port of the APISIX server accepting the L4 connection
+    /// Example: 9100
+    pub fn with_server_port(mut self, server_port: i64) -> Self {
+        self.server_port = Some(server_port);
+        self
+    }
+
+    /// Server Name Indication, used to route TLS connections
+    pub fn with_sni(mut self, sni: String) -> Self {
+        self.sni = Some(sni);
+        self
+    }
+
+    /// Configuration of the Upstream [@UpstreamRequest], with a tcp, udp or tls scheme
+    pub fn with_upstream(mut self, upstream: UpstreamRequest) -> Self {
+        self.upstream = Some(upstream);
+        self
+    }
+
+    /// Id of the Upstream service
+    pub fn with_upstream_id(mut self, upstream_id: String) -> Self {
+        self.upstream_id = Some(upstream_id);
+        self
+    }
+
+    /// Id of the bound Service
+    pub fn with_service_id(mut self, service_id: String) -> Self {
+        self.service_id = Some(service_id);
+        self
+    }
+
+    /// Stream plugins executed for the connection. See [StreamPlugins] for more
+    pub fn with_plugins(mut self, plugins: StreamPlugins) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
+
+    pub fn build(self) -> Result<StreamRouteRequest> {
+        Ok(StreamRouteRequest {
+            id: self.id,
+            desc: self.desc,
+            remote_addr: self.remote_addr,
+            server_addr: self.server_addr,
+            server_port: self.server_port,
+            sni: self.sni,
+            upstream: self.upstream,
+            upstream_id: self.upstream_id,
+            service_id: self.service_id,
+            plugins: self.plugins,
+        })
+    }
+}
+
+/// A Stream Route proxies L4 (TCP/UDP) traffic. Requires the stream proxy to be enabled in APISIX
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamRouteRequest {
+    pub id: Option<String>,
+    pub desc: Option<String>,
+    pub remote_addr: Option<String>,
+    pub server_addr: Option<String>,
+    pub server_port: Option<i64>,
+    pub sni: Option<String>,
+    pub upstream: Option<UpstreamRequest>,
+    pub upstream_id: Option<String>,
+    pub service_id: Option<String>,
+    pub plugins: Option<StreamPlugins>,
+}
+
+impl Default for StreamRouteRequest {
+    fn default() -> Self {
+        StreamRouteRequest {
+            id: Some(generate_identifier()),
+            desc: None,
+            remote_addr: None,
+            server_addr: None,
+            server_port: None,
+            sni: None,
+            upstream: None,
+            upstream_id: None,
+            service_id: None,
+            plugins: None,
+        }
+    }
+}
+
+impl From<StreamRouteRequest> for StreamRouteBuilder {
+    fn from(stream_route: StreamRouteRequest) -> Self {
+        StreamRouteBuilder {
+            id: stream_route.id,
+            desc: stream_route.desc,
+            remote_addr: stream_route.remote_addr,
+            server_addr: stream_route.server_addr,
+            server_port: stream_route.server_port,
+            sni: stream_route.sni,
+            upstream: stream_route.upstream,
+            upstream_id: stream_route.upstream_id,
+            service_id: stream_route.service_id,
+            plugins: stream_route.plugins,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::models::StreamPlugins;
+use crate::UpstreamRequest;
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ApisixStreamRoute {
+    pub id: Option<Value>, //preferred String
+    pub desc: Option<String>,
+    pub remote_addr: Option<String>,
+    pub server_addr: Option<String>,
+    pub server_port: Option<i64>,
+    pub sni: Option<String>,
+    pub upstream: Option<UpstreamRequest>,
+    pub upstream_id: Option<Value>, //preferred String
+    pub service_id: Option<Value>, //preferred String
+    pub plugins: Option<StreamPlugins>,
+    pub create_time: Option<i64>,
+    pub update_time: Option<i64>,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::common::TypedItem;
+    use crate::models::UpstreamSchema;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_stream_route_response() {
+        let nodes = r#"
+        {
+            "createdIndex": 610,
+            "key": "/apisix/stream_routes/mqtt",
+            "value": {
+                "id": "mqtt",
+                "server_port": 1883,
+                "plugins": {
+                    "mqtt-proxy": {
+                        "protocol_name": "MQTT",
+                        "protocol_level": 4
+                    }
+                },
+                "upstream": {
+                    "type": "chash",
+                    "scheme": "tcp",
+                    "nodes": { "127.0.0.1:1980": 1 }
+                },
+                "create_time": 1727776498,
+                "update_time": 1727776498
+            },
+            "modifiedIndex": 610
+        }"#;
+        let nodes: TypedItem<ApisixStreamRoute> = serde_json::from_str(nodes).unwrap();
+        let stream_route = nodes.value.unwrap();
+        assert_eq!(stream_route.id.unwrap(), "mqtt");
+        assert_eq!(stream_route.server_port.unwrap(), 1883);
+        assert_eq!(stream_route.plugins.unwrap().mqtt_proxy.unwrap().protocol_level.unwrap(), 4);
+        assert_eq!(stream_route.upstream.unwrap().scheme.unwrap(), UpstreamSchema::tcp);
+    }
+}
+// endregion: tests
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use strum_macros::{Display, EnumString};
@@ -20,6 +21,7 @@ pub struct UpstreamBuilder {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub scheme: Option<UpstreamSchema>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl UpstreamBuilder {
@@ -117,6 +119,18 @@ impl UpstreamBuilder {
         self
     }
 
+    /// Attributes of the Upstream specified as key-value pairs, e.g. team and environment
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Adds a single label to the Upstream
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(&self) -> Result<UpstreamRequest> {
         Ok(UpstreamRequest {
             id: self.id.clone(),
@@ -130,6 +144,7 @@ impl UpstreamBuilder {
             name: self.name.clone(),
             desc: self.desc.clone(),
             scheme: self.scheme.clone(),
+            labels: self.labels.clone(),
         })
     }
 
@@ -151,6 +166,7 @@ pub struct UpstreamRequest {
     pub name: Option<String>,
     pub desc: Option<String>,
     pub scheme: Option<UpstreamSchema>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl Default for UpstreamRequest {
@@ -172,6 +188,7 @@ impl Default for UpstreamRequest {
             name: None,
             desc: None,
             scheme: Some(UpstreamSchema::http),
+            labels: None,
         }
     }
 }
@@ -190,6 +207,7 @@ impl From<UpstreamRequest> for UpstreamBuilder {
             name: upstream.name,
             desc: upstream.desc,
             scheme: upstream.scheme,
+            labels: upstream.labels,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -13,6 +14,7 @@ pub struct ApisixUpstream {
     pub update_time: Option<i64>,
     pub name: Option<String>,
     pub id: Option<Value>, //can be both string or integer
+    pub labels: Option<HashMap<String, String>>,
 }
 
 // region: tests
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::models::{generate_identifier, Plugins};
 use crate::{Result};
@@ -8,6 +9,7 @@ pub struct ConsumerGroupBuilder {
     pub plugins: Plugins,
     pub id: Option<String>,
     pub desc: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl ConsumerGroupBuilder {
@@ -32,11 +34,24 @@ impl ConsumerGroupBuilder {
         self
     }
 
+    /// Attributes of the Consumer group specified as key-value pairs, e.g. team and environment
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Adds a single label to the Consumer group
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(&self) -> Result<ConsumerGroupRequest> {
         Ok(ConsumerGroupRequest {
             plugins: self.plugins.clone(),
             id: self.id.clone(),
             desc: self.desc.clone(),
+            labels: self.labels.clone(),
         })
     }
 }
@@ -47,6 +62,7 @@ pub struct ConsumerGroupRequest {
     pub plugins: Plugins,
     pub id: Option<String>,
     pub desc: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl Default for ConsumerGroupRequest {
@@ -55,6 +71,7 @@ impl Default for ConsumerGroupRequest {
             plugins: Plugins::default(),
             id: Some(generate_identifier()),
             desc: None,
+            labels: None,
         }
     }
 }
@@ -65,6 +82,7 @@ impl From<ConsumerGroupRequest> for ConsumerGroupBuilder {
             plugins: consumer_group.plugins,
             id: consumer_group.id,
             desc: consumer_group.desc,
+            labels: consumer_group.labels,
         }
     }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::models::Plugins;
 
@@ -9,6 +10,7 @@ pub struct ApisixConsumerGroup {
     pub desc: Option<String>,
     pub update_time: Option<i64>,
     pub create_time: Option<i64>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 
@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::{Result};
-use crate::models::Plugins;
+use crate::models::{ApisixConsumer, Plugins};
 
 #[serde_with::skip_serializing_none]
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -9,6 +10,7 @@ pub struct ConsumerBuilder {
     pub username: Option<String>,
     pub group_id: Option<String>,
     pub desc: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl ConsumerBuilder {
@@ -40,12 +42,26 @@ impl ConsumerBuilder {
         self.plugins = Some(plugins);
         self
     }
+
+    /// Attributes of the Consumer specified as key-value pairs, e.g. team and environment
+    pub fn with_labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Adds a single label to the Consumer
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> Result<ConsumerRequest> {
         Ok(ConsumerRequest {
             plugins: self.plugins,
             username: self.username,
             group_id: self.group_id,
             desc: self.desc,
+            labels: self.labels,
         })
     }
 }
@@ -57,6 +73,7 @@ pub struct ConsumerRequest {
     pub username: Option<String>,
     pub group_id: Option<String>,
     pub desc: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 impl From<ConsumerRequest> for ConsumerBuilder {
@@ -66,6 +83,19 @@ impl From<ConsumerRequest> for ConsumerBuilder {
             username: consumer.username,
             group_id: consumer.group_id,
             desc: consumer.desc,
+            labels: consumer.labels,
+        }
+    }
+}
+
+impl From<ApisixConsumer> for ConsumerRequest {
+    fn from(consumer: ApisixConsumer) -> Self {
+        ConsumerRequest {
+            plugins: consumer.plugins,
+            username: consumer.username,
+            group_id: consumer.group_id,
+            desc: consumer.desc,
+            labels: consumer.labels,
         }
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::models::Plugins;
 
@@ -10,6 +11,7 @@ pub struct ApisixConsumer {
     pub desc: Option<String>,
     pub create_time: Option<i64>,
     pub group_id: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 
@@ -48,6 +50,7 @@ mod tests {
         assert_eq!(nodes.value.clone().unwrap().group_id.unwrap(), "trust1team");
         assert_eq!(nodes.value.clone().unwrap().create_time.unwrap(), 1727847549);
         assert_eq!(nodes.value.clone().unwrap().update_time.unwrap(), 1727847549);
+        assert_eq!(nodes.value.clone().unwrap().plugins.unwrap().key_auth.unwrap().key.unwrap(), "7b034e79-2cb9-4e17-af10-ff1aad082307");
     }
 }
 // endregion: tests
\ No newline at end of file
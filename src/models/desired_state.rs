@@ -0,0 +1,498 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumString};
+use crate::error::ApisixClientError;
+use crate::models::{sort_for_apply, AdminObject, ConsumerGroupRequest, ConsumerRequest, GlobalRuleRequest, PluginConfigRequest, ProtoRequest, ResourceKind, RouteRequest, ServiceRequest, SslRequest, StreamRouteRequest, UpstreamRequest};
+use crate::{Result};
+
+/// Raw live objects, keyed by kind then by id, see [crate::client::AdminConnector::live_state]
+pub type LiveState = BTreeMap<ResourceKind, BTreeMap<String, Value>>;
+
+/// Indexes raw objects by kind and by the attribute holding their id, objects without id are skipped
+pub fn index_live_state(objects: impl IntoIterator<Item = (ResourceKind, Vec<Value>)>) -> LiveState {
+    objects.into_iter()
+        .map(|(kind, values)| (kind, values.into_iter().filter_map(|v| live_id(kind, &v).map(|id| (id, v))).collect()))
+        .collect()
+}
+
+/// Server managed attributes, never compared
+pub const SERVER_MANAGED_FIELDS: [&str; 4] = ["create_time", "update_time", "validity_start", "validity_end"];
+
+const PLUGIN_HOLDERS: [ResourceKind; 6] = [
+    ResourceKind::routes, ResourceKind::services, ResourceKind::global_rules,
+    ResourceKind::plugin_configs, ResourceKind::consumer_groups, ResourceKind::consumers,
+];
+const INLINE_UPSTREAM_HOLDERS: [ResourceKind; 3] = [ResourceKind::routes, ResourceKind::services, ResourceKind::stream_routes];
+
+/// Attributes that Apisix fills in with their default value when they are not set,
+/// as `(kinds, JSON pointer in the object, JSON value)`
+///
+/// An attribute holding its default is not reported when it is missing on the other side.
+pub const SERVER_DEFAULTS: [(&[ResourceKind], &str, &str); 30] = [
+    (&[ResourceKind::routes, ResourceKind::ssls], "/status", "1"),
+    (&[ResourceKind::routes], "/priority", "0"),
+    (&[ResourceKind::ssls], "/type", "\"server\""),
+    (&[ResourceKind::upstreams], "/scheme", "\"http\""),
+    (&[ResourceKind::upstreams], "/pass_host", "\"pass\""),
+    (&[ResourceKind::upstreams], "/hash_on", "\"vars\""),
+    (&[ResourceKind::upstreams], "/type", "\"roundrobin\""),
+    (&INLINE_UPSTREAM_HOLDERS, "/upstream/scheme", "\"http\""),
+    (&INLINE_UPSTREAM_HOLDERS, "/upstream/pass_host", "\"pass\""),
+    (&INLINE_UPSTREAM_HOLDERS, "/upstream/hash_on", "\"vars\""),
+    (&INLINE_UPSTREAM_HOLDERS, "/upstream/type", "\"roundrobin\""),
+    (&PLUGIN_HOLDERS, "/plugins/consumer-restriction/type", "\"consumer_name\""),
+    (&PLUGIN_HOLDERS, "/plugins/consumer-restriction/rejected_code", "403"),
+    (&PLUGIN_HOLDERS, "/plugins/key-auth/header", "\"apikey\""),
+    (&PLUGIN_HOLDERS, "/plugins/key-auth/query", "\"apikey\""),
+    (&PLUGIN_HOLDERS, "/plugins/key-auth/hide_credentials", "false"),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/header", "\"authorization\""),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/query", "\"jwt\""),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/cookie", "\"jwt\""),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/hide_credentials", "false"),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/key_claim_name", "\"key\""),
+    (&PLUGIN_HOLDERS, "/plugins/jwt-auth/store_in_ctx", "false"),
+    (&[ResourceKind::consumers, ResourceKind::credentials], "/plugins/jwt-auth/algorithm", "\"HS256\""),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/policy", "\"local\""),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/key", "\"remote_addr\""),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/key_type", "\"var\""),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/rejected_code", "503"),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/allow_degradation", "false"),
+    (&PLUGIN_HOLDERS, "/plugins/limit-count/show_limit_quota_header", "true"),
+    (&PLUGIN_HOLDERS, "/plugins/proxy-rewrite/use_real_request_uri_unsafe", "false"),
+];
+
+/// True when the attribute at `pointer` of an object of the kind holds the value Apisix sets by default,
+/// see [SERVER_DEFAULTS]
+pub(crate) fn is_server_default(kind: ResourceKind, pointer: &str, value: &Value) -> bool {
+    let value = value.to_string();
+    SERVER_DEFAULTS.iter().any(|(kinds, p, v)| kinds.contains(&kind) && *p == pointer && *v == value)
+}
+
+/// Label marking the objects owned by a declarative configuration, see pruning
+pub const OWNER_LABEL: &str = "managed-by";
+
+/// Declarative description of the gateway, read from YAML or JSON
+///
+/// Only the kinds present in the file are managed: a kind that is absent is left untouched,
+/// a kind declared with an empty list means that every live object of that kind is unwanted.
+/// ```yaml
+/// upstreams:
+///   - id: payments
+///     type: roundrobin
+///     nodes: { "payments.svc:8080": 1 }
+/// routes:
+///   - id: payments-api
+///     uri: /payments/*
+///     upstream_id: payments
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DesiredState {
+    pub ssls: Option<Vec<SslRequest>>,
+    pub protos: Option<Vec<ProtoRequest>>,
+    pub upstreams: Option<Vec<UpstreamRequest>>,
+    pub services: Option<Vec<ServiceRequest>>,
+    pub plugin_configs: Option<Vec<PluginConfigRequest>>,
+    pub global_rules: Option<Vec<GlobalRuleRequest>>,
+    pub consumer_groups: Option<Vec<ConsumerGroupRequest>>,
+    pub consumers: Option<Vec<ConsumerRequest>>,
+    pub routes: Option<Vec<RouteRequest>>,
+    pub stream_routes: Option<Vec<StreamRouteRequest>>,
+}
+
+impl DesiredState {
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        serde_yaml::from_str(yaml).map_err(|e| ApisixClientError::InvalidRequest(format!("invalid desired state: {}", e)))
+    }
+
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| ApisixClientError::InvalidRequest(format!("invalid desired state: {}", e)))
+    }
+
+    /// Reads a `.json` file as JSON, any other file as YAML
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("unable to read {}: {}", path.display(), e)))?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            DesiredState::from_json_str(&content)
+        } else {
+            DesiredState::from_yaml_str(&content)
+        }
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+
+    /// Builds a desired state holding the objects, every kind of [ResourceKind::TYPED] is managed
+    pub fn from_objects(objects: impl IntoIterator<Item = AdminObject>) -> Self {
+        let mut state = DesiredState {
+            ssls: Some(vec![]),
+            protos: Some(vec![]),
+            upstreams: Some(vec![]),
+            services: Some(vec![]),
+            plugin_configs: Some(vec![]),
+            global_rules: Some(vec![]),
+            consumer_groups: Some(vec![]),
+            consumers: Some(vec![]),
+            routes: Some(vec![]),
+            stream_routes: Some(vec![]),
+        };
+        for object in objects {
+            state.push(object);
+        }
+        state
+    }
+
+    /// Adds an object, managing its kind when it was not yet
+    pub fn push(&mut self, object: AdminObject) {
+        match object {
+            AdminObject::Ssl(r) => self.ssls.get_or_insert_with(Vec::new).push(r),
+            AdminObject::Proto(r) => self.protos.get_or_insert_with(Vec::new).push(r),
+            AdminObject::Upstream(r) => self.upstreams.get_or_insert_with(Vec::new).push(r),
+            AdminObject::Service(r) => self.services.get_or_insert_with(Vec::new).push(r),
+            AdminObject::PluginConfig(r) => self.plugin_configs.get_or_insert_with(Vec::new).push(r),
+            AdminObject::GlobalRule(r) => self.global_rules.get_or_insert_with(Vec::new).push(r),
+            AdminObject::ConsumerGroup(r) => self.consumer_groups.get_or_insert_with(Vec::new).push(r),
+            AdminObject::Consumer(r) => self.consumers.get_or_insert_with(Vec::new).push(r),
+            AdminObject::Route(r) => self.routes.get_or_insert_with(Vec::new).push(r),
+            AdminObject::StreamRoute(r) => self.stream_routes.get_or_insert_with(Vec::new).push(r),
+        }
+    }
+
+    /// Kinds declared in the desired state, in [ResourceKind::APPLY_ORDER]
+    pub fn managed_kinds(&self) -> Vec<ResourceKind> {
+        let declared = |kind: &ResourceKind| match kind {
+            ResourceKind::ssls => self.ssls.is_some(),
+            ResourceKind::protos => self.protos.is_some(),
+            ResourceKind::upstreams => self.upstreams.is_some(),
+            ResourceKind::services => self.services.is_some(),
+            ResourceKind::plugin_configs => self.plugin_configs.is_some(),
+            ResourceKind::global_rules => self.global_rules.is_some(),
+            ResourceKind::consumer_groups => self.consumer_groups.is_some(),
+            ResourceKind::consumers => self.consumers.is_some(),
+            ResourceKind::routes => self.routes.is_some(),
+            ResourceKind::stream_routes => self.stream_routes.is_some(),
+            ResourceKind::secrets | ResourceKind::plugin_metadata | ResourceKind::credentials => false,
+        };
+        ResourceKind::APPLY_ORDER.into_iter().filter(declared).collect()
+    }
+
+    /// All the declared objects, in dependency order
+    pub fn objects(&self) -> Vec<AdminObject> {
+        fn wrap<T: Clone + Into<AdminObject>>(items: &Option<Vec<T>>) -> Vec<AdminObject> {
+            items.iter().flatten().cloned().map(Into::into).collect()
+        }
+        let mut objects = [
+            wrap(&self.ssls), wrap(&self.protos), wrap(&self.upstreams), wrap(&self.services),
+            wrap(&self.plugin_configs), wrap(&self.global_rules), wrap(&self.consumer_groups),
+            wrap(&self.consumers), wrap(&self.routes), wrap(&self.stream_routes),
+        ].concat();
+        sort_for_apply(&mut objects);
+        objects
+    }
+
+    /// Checks that every object has an id and that ids are unique per kind
+    pub fn validate(&self) -> Result<()> {
+        let mut seen = HashSet::new();
+        for object in self.objects() {
+            let kind = object.kind();
+            let id = object.id().ok_or(ApisixClientError::InvalidRequest(format!("{} object without {}", kind, kind.id_field())))?;
+            if !seen.insert((kind, id.to_string())) {
+                return Err(ApisixClientError::InvalidRequest(format!("duplicate {} id: {}", kind, id)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares the desired state with the live objects of the managed kinds
+    ///
+    /// Attributes are compared in both directions, e.g. a plugin or an upstream node only present on the
+    /// live object gives an update. The [SERVER_MANAGED_FIELDS] are ignored, as are the attributes only
+    /// present on the live object that hold their Apisix default, see [SERVER_DEFAULTS].
+    pub fn plan(&self, live: &LiveState) -> Result<Plan> {
+        self.validate()?;
+        let managed = self.managed_kinds();
+        let mut actions = vec![];
+        let mut desired_ids = HashSet::new();
+        for object in self.objects() {
+            let kind = object.kind();
+            let id = object.id().unwrap_or_default().to_string();
+            desired_ids.insert((kind, id.clone()));
+            let desired = object.to_value()?;
+            let current = live.get(&kind).and_then(|objects| objects.get(&id));
+            let (op, diffs) = match current {
+                None => (PlanOp::create, vec![]),
+                Some(current) => {
+                    let mut diffs = vec![];
+                    diff_values("", &desired, current, Some(kind), &mut diffs);
+                    if diffs.is_empty() { (PlanOp::noop, diffs) } else { (PlanOp::update, diffs) }
+                },
+            };
+            actions.push(PlanAction { kind, id, op, diffs, desired: Some(object), live: current.cloned() });
+        }
+        let mut deletes = vec![];
+        for kind in &managed {
+            for (id, current) in live.get(kind).into_iter().flatten() {
+                if !desired_ids.contains(&(*kind, id.clone())) {
+                    deletes.push(PlanAction { kind: *kind, id: id.clone(), op: PlanOp::delete, diffs: vec![], desired: None, live: Some(current.clone()) });
+                }
+            }
+        }
+        deletes.reverse();
+        actions.extend(deletes);
+        Ok(Plan { actions })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum PlanOp {
+    create,
+    update,
+    delete,
+    noop,
+}
+
+/// Difference on a single attribute, `path` is a JSON pointer (e.g. `/upstream/nodes/0/weight`)
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDiff {
+    pub path: String,
+    pub live: Option<Value>,
+    pub desired: Option<Value>,
+}
+
+/// Action needed to converge a single object
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanAction {
+    pub kind: ResourceKind,
+    pub id: String,
+    pub op: PlanOp,
+    pub diffs: Vec<FieldDiff>,
+    pub desired: Option<AdminObject>,
+    pub live: Option<Value>,
+}
+
+/// Actions to converge the gateway to a [DesiredState]
+/// Creates and updates come in dependency order, followed by the deletes in reverse dependency order
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    pub actions: Vec<PlanAction>,
+}
+
+impl Plan {
+    /// Actions changing the gateway, no-ops excluded
+    pub fn changes(&self) -> impl Iterator<Item = &PlanAction> {
+        self.actions.iter().filter(|a| a.op != PlanOp::noop)
+    }
+
+    pub fn by_op(&self, op: PlanOp) -> impl Iterator<Item = &PlanAction> {
+        self.actions.iter().filter(move |a| a.op == op)
+    }
+
+    /// True when the gateway already matches the desired state
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for action in self.changes() {
+            let sign = match action.op {
+                PlanOp::create => "+",
+                PlanOp::update => "~",
+                PlanOp::delete => "-",
+                _ => " ",
+            };
+            writeln!(f, "{} {}/{}", sign, action.kind, action.id)?;
+            for diff in &action.diffs {
+                writeln!(f, "    {}: {} => {}", diff.path, display_value(&diff.live), display_value(&diff.desired))?;
+            }
+        }
+        let count = |op| self.by_op(op).count();
+        write!(f, "{} to create, {} to update, {} to delete, {} unchanged",
+            count(PlanOp::create), count(PlanOp::update), count(PlanOp::delete), count(PlanOp::noop))
+    }
+}
+
+pub(crate) fn display_value(value: &Option<Value>) -> String {
+    value.as_ref().map(Value::to_string).unwrap_or_else(|| "(none)".to_string())
+}
+
+pub(crate) fn live_id(kind: ResourceKind, value: &Value) -> Option<String> {
+    match value.get(kind.id_field())? {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// Escapes a key as a JSON pointer token
+pub(crate) fn pointer_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Collects the attributes that differ between `desired` and `live`, in both directions
+///
+/// The top level [SERVER_MANAGED_FIELDS] are skipped. With `defaults` set to the kind of the objects, attributes
+/// only present on `live` are skipped when they hold their Apisix default, see [SERVER_DEFAULTS]. Arrays of the
+/// same length are compared item by item, otherwise as a whole.
+pub(crate) fn diff_values(path: &str, desired: &Value, live: &Value, defaults: Option<ResourceKind>, diffs: &mut Vec<FieldDiff>) {
+    match (desired, live) {
+        (Value::Object(desired), Value::Object(live)) => {
+            let managed = |key: &str| path.is_empty() && SERVER_MANAGED_FIELDS.contains(&key);
+            for (key, value) in desired.iter().filter(|(key, _)| !managed(key)) {
+                let child = format!("{}/{}", path, pointer_token(key));
+                match live.get(key) {
+                    Some(current) => diff_values(&child, value, current, defaults, diffs),
+                    None => diffs.push(FieldDiff { path: child, live: None, desired: Some(value.clone()) }),
+                }
+            }
+            let extra = live.iter()
+                .filter(|(key, value)| !desired.contains_key(*key) && !managed(key) && !value.is_null())
+                .map(|(key, value)| (format!("{}/{}", path, pointer_token(key)), value))
+                .filter(|(child, value)| !defaults.is_some_and(|kind| is_server_default(kind, child, value)));
+            for (child, value) in extra {
+                diffs.push(FieldDiff { path: child, live: Some(value.clone()), desired: None });
+            }
+        },
+        (Value::Array(desired), Value::Array(live)) if desired.len() == live.len() => {
+            for (index, (value, current)) in desired.iter().zip(live).enumerate() {
+                diff_values(&format!("{}/{}", path, index), value, current, defaults, diffs);
+            }
+        },
+        (Value::String(d), Value::Number(l)) | (Value::Number(l), Value::String(d)) if *d == l.to_string() => {},
+        (Value::Number(d), Value::Number(l)) if d.as_f64() == l.as_f64() => {},
+        _ if desired == live => {},
+        _ => diffs.push(FieldDiff { path: path.to_string(), live: Some(live.clone()), desired: Some(desired.clone()) }),
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+
+    const STATE: &str = r#"
+upstreams:
+  - id: payments
+    type: roundrobin
+    nodes:
+      "payments.svc:8080": 1
+routes:
+  - id: payments-api
+    uri: /payments/*
+    upstream_id: payments
+    labels:
+      team: payments
+consumers: []
+"#;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_desired_state_yaml() {
+        let state = DesiredState::from_yaml_str(STATE).unwrap();
+        assert_eq!(state.managed_kinds(), vec![ResourceKind::upstreams, ResourceKind::consumers, ResourceKind::routes]);
+        assert_eq!(state.objects()[0].kind(), ResourceKind::upstreams);
+        let json = state.to_json().unwrap();
+        assert_eq!(DesiredState::from_json_str(&json).unwrap(), state);
+        assert!(DesiredState::from_yaml_str("unknown: []").is_err());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_plan() {
+        let state = DesiredState::from_yaml_str(STATE).unwrap();
+        let live = index_live_state([
+            (ResourceKind::upstreams, vec![json!({
+                "id": "payments", "type": "roundrobin", "nodes": {"payments.svc:8080": 1},
+                "scheme": "http", "pass_host": "pass", "create_time": 1, "update_time": 2
+            })]),
+            (ResourceKind::routes, vec![json!({
+                "id": "payments-api", "uri": "/payments/v1/*", "upstream_id": "payments", "status": 1
+            })]),
+            (ResourceKind::consumers, vec![json!({"username": "jack"})]),
+            (ResourceKind::services, vec![json!({"id": "unmanaged"})]),
+        ]);
+        let plan = state.plan(&live).unwrap();
+        assert_eq!(plan.by_op(PlanOp::noop).next().unwrap().kind, ResourceKind::upstreams);
+        let update = plan.by_op(PlanOp::update).next().unwrap();
+        assert_eq!(update.id, "payments-api");
+        assert_eq!(update.diffs, vec![
+            FieldDiff { path: "/labels".to_string(), live: None, desired: Some(json!({"team": "payments"})) },
+            FieldDiff { path: "/uri".to_string(), live: Some(json!("/payments/v1/*")), desired: Some(json!("/payments/*")) },
+        ]);
+        let delete = plan.by_op(PlanOp::delete).collect::<Vec<_>>();
+        assert_eq!(delete.len(), 1);
+        assert_eq!(delete[0].id, "jack");
+        assert!(plan.to_string().ends_with("0 to create, 1 to update, 1 to delete, 1 unchanged"));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_plan_live_extras() {
+        let state = DesiredState::from_yaml_str(STATE).unwrap();
+        let live = index_live_state([
+            (ResourceKind::upstreams, vec![json!({
+                "id": "payments", "type": "roundrobin", "scheme": "http",
+                "nodes": {"payments.svc:8080": 1, "rogue.svc:8080": 1}
+            })]),
+            (ResourceKind::routes, vec![json!({
+                "id": "payments-api", "uri": "/payments/*", "upstream_id": "payments", "labels": {"team": "payments"},
+                "priority": 0, "plugins": {"key-auth": {"header": "apikey"}}
+            })]),
+        ]);
+        let plan = state.plan(&live).unwrap();
+        let updates: Vec<_> = plan.by_op(PlanOp::update).collect();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].diffs, vec![
+            FieldDiff { path: "/nodes/rogue.svc:8080".to_string(), live: Some(json!(1)), desired: None },
+        ]);
+        assert_eq!(updates[1].diffs, vec![
+            FieldDiff { path: "/plugins".to_string(), live: Some(json!({"key-auth": {"header": "apikey"}})), desired: None },
+        ]);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_plan_defaults_by_path() {
+        let state = DesiredState::from_yaml_str(r#"
+routes:
+  - id: r1
+    uri: /a
+    upstream_id: payments
+    plugins:
+      limit-count: { count: 10, time_window: 60 }
+"#).unwrap();
+        let live = index_live_state([(ResourceKind::routes, vec![json!({
+            "id": "r1", "uri": "/a", "upstream_id": "payments", "status": 1,
+            "plugins": {"limit-count": {"count": 10, "time_window": 60, "policy": "local", "key": "remote_addr"}},
+        })])]);
+        assert!(state.plan(&live).unwrap().is_empty());
+
+        // same names and values as defaults, elsewhere in the object
+        let live = index_live_state([(ResourceKind::routes, vec![json!({
+            "id": "r1", "uri": "/a", "upstream_id": "payments", "labels": {"status": "1"}, "key": "remote_addr",
+            "plugins": {"limit-count": {"count": 10, "time_window": 60, "rejected_code": 403}},
+        })])]);
+        let plan = state.plan(&live).unwrap();
+        let paths: Vec<&str> = plan.by_op(PlanOp::update).flat_map(|a| a.diffs.iter().map(|d| d.path.as_str())).collect();
+        assert_eq!(paths, vec!["/plugins/limit-count/rejected_code", "/key", "/labels"]);
+    }
+}
+// endregion: tests
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumString};
+use crate::error::ApisixClientError;
+use crate::models::{diff_values, display_value, DesiredState, FieldDiff, LiveState, PlanOp, ResourceKind};
+use crate::Result;
+
+/// Raw objects of the gateway at a point in time, used as the baseline of a [DriftReport]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Seconds since the Unix epoch
+    pub taken_at: u64,
+    /// Raw objects keyed by kind then by id
+    pub objects: LiveState,
+}
+
+impl Snapshot {
+    pub fn new(objects: impl IntoIterator<Item = (ResourceKind, BTreeMap<String, Value>)>) -> Self {
+        Snapshot {
+            taken_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default(),
+            objects: objects.into_iter().collect(),
+        }
+    }
+
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| ApisixClientError::InvalidRequest(format!("invalid snapshot: {}", e)))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+
+    pub fn kinds(&self) -> Vec<ResourceKind> {
+        self.objects.keys().copied().collect()
+    }
+
+    /// Compares the live objects with the snapshot, kinds missing from the snapshot are not compared
+    ///
+    /// Every attribute is compared except the server managed ones, see [crate::models::SERVER_MANAGED_FIELDS].
+    pub fn drift(&self, live: &LiveState) -> DriftReport {
+        let mut items = vec![];
+        let none = BTreeMap::new();
+        for (kind, baseline) in &self.objects {
+            let kind = *kind;
+            let current = live.get(&kind).unwrap_or(&none);
+            for (id, expected) in baseline {
+                let id = id.clone();
+                match current.get(&id) {
+                    None => items.push(DriftItem { kind, id, change: DriftChange::removed, diffs: vec![] }),
+                    Some(actual) => {
+                        let mut diffs = vec![];
+                        diff_values("", expected, actual, None, &mut diffs);
+                        if !diffs.is_empty() {
+                            items.push(DriftItem { kind, id, change: DriftChange::modified, diffs });
+                        }
+                    },
+                }
+            }
+            for id in current.keys().filter(|id| !baseline.contains_key(*id)) {
+                items.push(DriftItem { kind, id: id.clone(), change: DriftChange::added, diffs: vec![] });
+            }
+        }
+        DriftReport { items }
+    }
+}
+
+impl DesiredState {
+    /// Compares the live objects with the desired state, see [DesiredState::plan] for what is compared
+    ///
+    /// Live objects of the managed kinds missing from the desired state are reported as added, and
+    /// attributes only present on a live object, e.g. an extra plugin, as modifications.
+    pub fn drift(&self, live: &LiveState) -> Result<DriftReport> {
+        let items = self.plan(live)?.actions.into_iter()
+            .filter_map(|action| {
+                let change = match action.op {
+                    PlanOp::create => DriftChange::removed,
+                    PlanOp::update => DriftChange::modified,
+                    PlanOp::delete => DriftChange::added,
+                    _ => return None,
+                };
+                Some(DriftItem { kind: action.kind, id: action.id, change, diffs: action.diffs })
+            })
+            .collect();
+        Ok(DriftReport { items })
+    }
+}
+
+/// How a live object differs from the baseline
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum DriftChange {
+    /// Live object missing from the baseline
+    added,
+    /// Baseline object missing from the gateway
+    removed,
+    modified,
+}
+
+/// Drifted object, for a modified object `diffs` holds the baseline value as `desired`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftItem {
+    pub kind: ResourceKind,
+    pub id: String,
+    pub change: DriftChange,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diffs: Vec<FieldDiff>,
+}
+
+/// Differences between a baseline and the live gateway
+/// Printed with `{}` for a terminal, serialized with [DriftReport::to_json] for tooling
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriftReport {
+    pub items: Vec<DriftItem>,
+}
+
+impl DriftReport {
+    /// True when the gateway matches the baseline
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn by_change(&self, change: DriftChange) -> impl Iterator<Item = &DriftItem> {
+        self.items.iter().filter(move |i| i.change == change)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.items {
+            let sign = match item.change {
+                DriftChange::added => "+",
+                DriftChange::removed => "-",
+                _ => "~",
+            };
+            writeln!(f, "{} {}/{} ({})", sign, item.kind, item.id, item.change)?;
+            for diff in &item.diffs {
+                writeln!(f, "    {}: {} => {}", diff.path, display_value(&diff.desired), display_value(&diff.live))?;
+            }
+        }
+        let count = |change| self.by_change(change).count();
+        write!(f, "{} added, {} removed, {} modified",
+            count(DriftChange::added), count(DriftChange::removed), count(DriftChange::modified))
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::models::index_live_state;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_snapshot_drift() {
+        let snapshot = Snapshot::new(index_live_state([
+            (ResourceKind::routes, vec![
+                json!({"id": "r1", "uri": "/a", "status": 1, "update_time": 1}),
+                json!({"id": "r2", "uri": "/b"}),
+            ]),
+            (ResourceKind::ssls, vec![]),
+        ]));
+        let live = index_live_state([
+            (ResourceKind::routes, vec![
+                json!({"id": "r1", "uri": "/a", "status": 0, "plugins": {"cors": {}}, "update_time": 2}),
+                json!({"id": 3, "uri": "/c"}),
+            ]),
+            (ResourceKind::upstreams, vec![json!({"id": "not-compared"})]),
+        ]);
+        let report = snapshot.drift(&live);
+        assert_eq!(report.by_change(DriftChange::added).next().unwrap().id, "3");
+        assert_eq!(report.by_change(DriftChange::removed).next().unwrap().id, "r2");
+        let modified = report.by_change(DriftChange::modified).next().unwrap();
+        let paths: Vec<&str> = modified.diffs.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, vec!["/status", "/plugins"]);
+        assert!(report.to_string().contains("    /status: 1 => 0\n"));
+        let json: Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["items"][0]["diffs"][0]["path"], "/status");
+        let restored = Snapshot::from_json_str(&snapshot.to_json().unwrap()).unwrap();
+        assert_eq!(restored.drift(&live), report);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_desired_drift_live_extras() {
+        let desired = DesiredState::from_yaml_str(r#"
+upstreams:
+  - id: u1
+    nodes: { "10.0.0.1:80": 1 }
+routes:
+  - id: r1
+    uri: /a
+    upstream_id: u1
+"#).unwrap();
+        let live = index_live_state([
+            (ResourceKind::upstreams, vec![json!({"id": "u1", "type": "roundrobin", "nodes": {"10.0.0.1:80": 1, "10.0.0.2:80": 1}})]),
+            (ResourceKind::routes, vec![
+                json!({"id": "r1", "uri": "/a", "upstream_id": "u1", "status": 1, "plugins": {"cors": {}}}),
+                json!({"id": "r2", "uri": "/b"}),
+            ]),
+        ]);
+        let report = desired.drift(&live).unwrap();
+        let modified: Vec<(&str, &str)> = report.by_change(DriftChange::modified)
+            .flat_map(|i| i.diffs.iter().map(|d| (i.id.as_str(), d.path.as_str())))
+            .collect();
+        assert_eq!(modified, vec![("u1", "/nodes/10.0.0.2:80"), ("r1", "/plugins")]);
+        assert_eq!(report.by_change(DriftChange::added).next().unwrap().id, "r2");
+    }
+}
+// endregion: tests
@@ -0,0 +1,644 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::error::ApisixClientError;
+use crate::models::common::ApisixTimeout;
+use crate::models::{AdminObject, ConsumerRequest, KeyAuth, Plugins, RouteRequest, UpstreamRequest, UpstreamSchema, UpstreamType};
+use crate::Result;
+
+/// API group and version of the Apisix Ingress Controller custom resources
+pub const CRD_API_VERSION: &str = "apisix.apache.org/v2";
+
+/// Spec of an Apisix Ingress Controller custom resource
+pub trait CrdSpec {
+    const KIND: &'static str;
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdMetadata {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub labels: Option<HashMap<String, String>>,
+    pub annotations: Option<HashMap<String, String>>,
+}
+
+/// Kubernetes manifest of an Apisix Ingress Controller custom resource (`apisix.apache.org/v2`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrdManifest<S> {
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: CrdMetadata,
+    pub spec: S,
+}
+
+pub type ApisixRouteCrd = CrdManifest<ApisixRouteSpec>;
+pub type ApisixUpstreamCrd = CrdManifest<ApisixUpstreamSpec>;
+pub type ApisixConsumerCrd = CrdManifest<ApisixConsumerSpec>;
+pub type ApisixPluginConfigCrd = CrdManifest<ApisixPluginConfigSpec>;
+
+impl<S: CrdSpec + Serialize + for<'de> Deserialize<'de>> CrdManifest<S> {
+    pub fn new(name: impl Into<String>, spec: S) -> Self {
+        CrdManifest {
+            api_version: CRD_API_VERSION.to_string(),
+            kind: S::KIND.to_string(),
+            metadata: CrdMetadata { name: name.into(), ..CrdMetadata::default() },
+            spec,
+        }
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.metadata.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))
+    }
+
+    /// Parses a single manifest, rejecting other kinds and API versions
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        let manifest: Self = serde_yaml::from_str(yaml)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("invalid {} manifest: {}", S::KIND, e)))?;
+        if manifest.api_version != CRD_API_VERSION || manifest.kind != S::KIND {
+            return Err(ApisixClientError::InvalidRequest(format!("expected {} {}, got {} {}",
+                CRD_API_VERSION, S::KIND, manifest.api_version, manifest.kind)));
+        }
+        Ok(manifest)
+    }
+}
+
+// region: common
+/// Plugin entry of a custom resource, `config` holds the plugin attributes as in the Admin API
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdPlugin {
+    pub name: String,
+    pub enable: bool,
+    pub config: Option<Value>,
+}
+
+/// Timeouts as durations, e.g. `15s`
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdTimeout {
+    pub connect: Option<String>,
+    pub send: Option<String>,
+    pub read: Option<String>,
+}
+
+impl From<&ApisixTimeout> for CrdTimeout {
+    fn from(timeout: &ApisixTimeout) -> Self {
+        let duration = |seconds: Option<f32>| seconds.map(|s| format!("{}s", s));
+        CrdTimeout { connect: duration(timeout.connect), send: duration(timeout.send), read: duration(timeout.read) }
+    }
+}
+
+impl TryFrom<&CrdTimeout> for ApisixTimeout {
+    type Error = ApisixClientError;
+
+    fn try_from(timeout: &CrdTimeout) -> Result<Self> {
+        Ok(ApisixTimeout {
+            connect: timeout.connect.as_deref().map(parse_duration).transpose()?,
+            send: timeout.send.as_deref().map(parse_duration).transpose()?,
+            read: timeout.read.as_deref().map(parse_duration).transpose()?,
+        })
+    }
+}
+
+/// Seconds of a Go duration as accepted by the Ingress Controller, such as `500ms`, `15s` or `1m30s`
+fn parse_duration(duration: &str) -> Result<f32> {
+    let invalid = || ApisixClientError::InvalidRequest(format!("invalid duration: {}", duration));
+    if duration == "0" {
+        return Ok(0.0);
+    }
+    if duration.is_empty() {
+        return Err(invalid());
+    }
+    let is_number = |c: char| c.is_ascii_digit() || c == '.';
+    let mut rest = duration;
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let (number, tail) = rest.split_at(rest.find(|c: char| !is_number(c)).unwrap_or(rest.len()));
+        let (unit, tail) = tail.split_at(tail.find(is_number).unwrap_or(tail.len()));
+        let factor = match unit {
+            "ns" => 1e-9,
+            "us" | "\u{b5}s" => 1e-6,
+            "ms" => 1e-3,
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3600.0,
+            _ => return Err(invalid()),
+        };
+        seconds += number.parse::<f64>().map_err(|_| invalid())? * factor;
+        rest = tail;
+    }
+    Ok(seconds as f32)
+}
+
+/// Plugins as custom resource entries, all enabled
+pub fn plugins_to_crd(plugins: &Plugins) -> Result<Vec<CrdPlugin>> {
+    let value = serde_json::to_value(plugins).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))?;
+    let Value::Object(plugins) = value else { return Ok(vec![]) };
+    Ok(plugins.into_iter()
+        .map(|(name, config)| CrdPlugin { name, enable: true, config: Some(config) })
+        .collect())
+}
+
+/// Enabled custom resource entries as plugins, plugins not modelled by [Plugins] are rejected
+pub fn plugins_from_crd(entries: &[CrdPlugin]) -> Result<Plugins> {
+    let enabled: Map<String, Value> = entries.iter()
+        .filter(|p| p.enable)
+        .map(|p| (p.name.clone(), p.config.clone().unwrap_or(Value::Object(Map::new()))))
+        .collect();
+    Plugins::from_configs(enabled)
+}
+
+/// Multi-document YAML with one manifest per Route, Upstream, Consumer and Plugin Config
+/// Other kinds have no custom resource and are rejected.
+pub fn objects_to_manifests(objects: &[AdminObject]) -> Result<String> {
+    let manifests = objects.iter()
+        .map(|object| match object {
+            AdminObject::Route(route) => ApisixRouteCrd::from_route(route)?.to_yaml(),
+            AdminObject::Upstream(upstream) => ApisixUpstreamCrd::from_upstream(upstream)?.to_yaml(),
+            AdminObject::Consumer(consumer) => ApisixConsumerCrd::from_consumer(consumer)?.to_yaml(),
+            AdminObject::PluginConfig(config) => {
+                let name = config.id.clone().ok_or(ApisixClientError::InvalidRequest("Plugin Config without id".to_string()))?;
+                ApisixPluginConfigCrd::from_plugins(name, &config.plugins)?.to_yaml()
+            },
+            _ => Err(unsupported("custom resource", &object.kind().to_string())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(manifests.join("---\n"))
+}
+
+fn unsupported(kind: &str, what: &str) -> ApisixClientError {
+    ApisixClientError::InvalidRequest(format!("{} has no {} equivalent", what, kind))
+}
+// endregion: common
+
+// region: ApisixRoute
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApisixRouteSpec {
+    pub ingress_class_name: Option<String>,
+    pub http: Vec<ApisixRouteHttp>,
+}
+
+impl CrdSpec for ApisixRouteSpec {
+    const KIND: &'static str = "ApisixRoute";
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApisixRouteHttp {
+    pub name: String,
+    pub priority: Option<i64>,
+    #[serde(rename = "match")]
+    pub match_field: ApisixRouteMatch,
+    /// Kubernetes Services, resolved by the Ingress Controller
+    pub backends: Option<Vec<Value>>,
+    /// [ApisixUpstreamCrd] with external nodes
+    pub upstreams: Option<Vec<ApisixRouteUpstreamRef>>,
+    pub websocket: Option<bool>,
+    pub timeout: Option<CrdTimeout>,
+    pub plugins: Option<Vec<CrdPlugin>>,
+    #[serde(rename = "plugin_config_name")]
+    pub plugin_config_name: Option<String>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApisixRouteMatch {
+    pub paths: Vec<String>,
+    pub methods: Option<Vec<String>>,
+    pub hosts: Option<Vec<String>>,
+    pub remote_addrs: Option<Vec<String>>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApisixRouteUpstreamRef {
+    pub name: String,
+    pub weight: Option<i64>,
+}
+
+impl ApisixRouteCrd {
+    /// Manifest named after the Route id, with a single rule
+    ///
+    /// The Route must reference its Upstream with `upstream_id`, to be declared as an [ApisixUpstreamCrd]:
+    /// inline Upstreams, Services and disabled Routes have no equivalent.
+    pub fn from_route(route: &RouteRequest) -> Result<Self> {
+        let name = route.id.clone().ok_or(ApisixClientError::InvalidRequest("Route without id".to_string()))?;
+        if route.upstream.is_some() {
+            return Err(unsupported(ApisixRouteSpec::KIND, "inline upstream"));
+        }
+        if route.service_id.is_some() {
+            return Err(unsupported(ApisixRouteSpec::KIND, "service_id"));
+        }
+        if route.status == Some(0) {
+            return Err(unsupported(ApisixRouteSpec::KIND, "disabled status"));
+        }
+        let paths: Vec<String> = route.uri.iter().chain(route.uris.iter().flatten()).cloned().collect();
+        if paths.is_empty() {
+            return Err(ApisixClientError::InvalidRequest(format!("Route {} without uri", name)));
+        }
+        let hosts: Vec<String> = route.host.iter().chain(route.hosts.iter().flatten()).cloned().collect();
+        let remote_addrs: Vec<String> = route.remote_addr.iter().chain(route.remote_addrs.iter().flatten()).cloned().collect();
+        let rule = ApisixRouteHttp {
+            name: name.clone(),
+            priority: route.priority,
+            match_field: ApisixRouteMatch {
+                paths,
+                methods: route.methods.clone(),
+                hosts: Some(hosts).filter(|h| !h.is_empty()),
+                remote_addrs: Some(remote_addrs).filter(|r| !r.is_empty()),
+            },
+            backends: None,
+            upstreams: route.upstream_id.clone().map(|name| vec![ApisixRouteUpstreamRef { name, weight: None }]),
+            websocket: route.enable_websocket,
+            timeout: route.timeout.as_ref().map(CrdTimeout::from),
+            plugins: route.plugins.as_ref().map(plugins_to_crd).transpose()?,
+            plugin_config_name: route.plugin_config_id.clone(),
+        };
+        let mut manifest = ApisixRouteCrd::new(name, ApisixRouteSpec { ingress_class_name: None, http: vec![rule] });
+        manifest.metadata.labels = route.labels.clone();
+        Ok(manifest)
+    }
+
+    /// One Route per rule, with the manifest name as id, or `<name>-<rule>` when the rule is named differently
+    ///
+    /// Rules routing to Kubernetes Services (`backends`) or to several upstreams are rejected.
+    pub fn to_routes(&self) -> Result<Vec<RouteRequest>> {
+        self.spec.http.iter()
+            .map(|rule| {
+                if rule.backends.as_ref().is_some_and(|b| !b.is_empty()) {
+                    return Err(unsupported("RouteRequest", "Kubernetes Service backend"));
+                }
+                let upstream_id = match rule.upstreams.as_deref() {
+                    None | Some([]) => None,
+                    Some([upstream]) => Some(upstream.name.clone()),
+                    Some(_) => return Err(unsupported("RouteRequest", "weighted upstreams")),
+                };
+                let id = if rule.name == self.metadata.name { rule.name.clone() } else { format!("{}-{}", self.metadata.name, rule.name) };
+                let mut paths = rule.match_field.paths.clone();
+                let (uri, uris) = if paths.len() == 1 { (paths.pop(), None) } else { (None, Some(paths)) };
+                Ok(RouteRequest {
+                    id: Some(id),
+                    name: None,
+                    desc: None,
+                    status: None,
+                    plugins: rule.plugins.as_deref().map(plugins_from_crd).transpose()?,
+                    plugin_config_id: rule.plugin_config_name.clone(),
+                    uri,
+                    uris,
+                    host: None,
+                    hosts: rule.match_field.hosts.clone(),
+                    remote_addr: None,
+                    remote_addrs: rule.match_field.remote_addrs.clone(),
+                    methods: rule.match_field.methods.clone(),
+                    upstream: None,
+                    upstream_id,
+                    service_id: None,
+                    timeout: rule.timeout.as_ref().map(ApisixTimeout::try_from).transpose()?,
+                    enable_websocket: rule.websocket,
+                    priority: rule.priority,
+                    labels: self.metadata.labels.clone(),
+                })
+            })
+            .collect()
+    }
+}
+// endregion: ApisixRoute
+
+// region: ApisixUpstream
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApisixUpstreamSpec {
+    pub external_nodes: Option<Vec<CrdExternalNode>>,
+    pub loadbalancer: Option<CrdLoadBalancer>,
+    pub scheme: Option<UpstreamSchema>,
+    pub retries: Option<i32>,
+    pub timeout: Option<CrdTimeout>,
+}
+
+impl CrdSpec for ApisixUpstreamSpec {
+    const KIND: &'static str = "ApisixUpstream";
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CrdExternalNodeType {
+    Domain,
+    Service,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdExternalNode {
+    #[serde(rename = "type")]
+    pub type_field: CrdExternalNodeType,
+    pub name: String,
+    pub port: Option<u16>,
+    pub weight: Option<i64>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrdLoadBalancer {
+    #[serde(rename = "type")]
+    pub type_field: UpstreamType,
+    pub hash_on: Option<String>,
+    pub key: Option<String>,
+}
+
+impl ApisixUpstreamCrd {
+    /// Manifest named after the Upstream id, the nodes become external `Domain` nodes
+    /// Upstreams using service discovery have no equivalent.
+    pub fn from_upstream(upstream: &UpstreamRequest) -> Result<Self> {
+        let name = upstream.id.clone().ok_or(ApisixClientError::InvalidRequest("Upstream without id".to_string()))?;
+        if upstream.service_name.is_some() || upstream.discovery_type.is_some() {
+            return Err(unsupported(ApisixUpstreamSpec::KIND, "service discovery"));
+        }
+        let spec = ApisixUpstreamSpec {
+            external_nodes: upstream.nodes.as_ref().map(external_nodes).transpose()?,
+            loadbalancer: upstream.type_field.clone().map(|type_field| CrdLoadBalancer { type_field, hash_on: None, key: None }),
+            scheme: upstream.scheme.clone(),
+            retries: upstream.retries,
+            timeout: upstream.timeout.as_ref().map(CrdTimeout::from),
+        };
+        let mut manifest = ApisixUpstreamCrd::new(name, spec);
+        manifest.metadata.labels = upstream.labels.clone();
+        Ok(manifest)
+    }
+
+    /// Upstream with the manifest name as id and the external `Domain` nodes as nodes
+    pub fn to_upstream(&self) -> Result<UpstreamRequest> {
+        let spec = &self.spec;
+        if spec.loadbalancer.as_ref().is_some_and(|lb| lb.hash_on.is_some() || lb.key.is_some()) {
+            return Err(unsupported("UpstreamRequest", "loadbalancer hashOn/key"));
+        }
+        let nodes = spec.external_nodes.iter().flatten()
+            .map(|node| match node.type_field {
+                CrdExternalNodeType::Domain => {
+                    let host = node.port.map(|port| format!("{}:{}", node.name, port)).unwrap_or(node.name.clone());
+                    Ok((host, Value::from(node.weight.unwrap_or(1))))
+                },
+                _ => Err(unsupported("UpstreamRequest", "Kubernetes Service external node")),
+            })
+            .collect::<Result<Map<String, Value>>>()?;
+        Ok(UpstreamRequest {
+            id: Some(self.metadata.name.clone()),
+            retries: spec.retries,
+            retry_timeout: None,
+            timeout: spec.timeout.as_ref().map(ApisixTimeout::try_from).transpose()?,
+            nodes: Some(Value::Object(nodes)),
+            service_name: None,
+            discovery_type: None,
+            type_field: spec.loadbalancer.as_ref().map(|lb| lb.type_field.clone()),
+            name: None,
+            desc: None,
+            scheme: spec.scheme.clone(),
+            labels: self.metadata.labels.clone(),
+        })
+    }
+}
+
+/// Nodes given as `{"host:port": weight}` or as `[{"host", "port", "weight"}]`
+fn external_nodes(nodes: &Value) -> Result<Vec<CrdExternalNode>> {
+    let invalid = || ApisixClientError::InvalidRequest(format!("invalid upstream nodes: {}", nodes));
+    let node = |host: &str, port: Option<u16>, weight: Option<i64>| {
+        let (name, port) = match (port, host.rsplit_once(':')) {
+            (None, Some((name, port))) => (name.to_string(), Some(port.parse().map_err(|_| invalid())?)),
+            _ => (host.to_string(), port),
+        };
+        Ok(CrdExternalNode { type_field: CrdExternalNodeType::Domain, name, port, weight })
+    };
+    match nodes {
+        Value::Object(map) => map.iter().map(|(host, weight)| node(host, None, weight.as_i64())).collect(),
+        Value::Array(list) => list.iter()
+            .map(|n| {
+                let host = n.get("host").and_then(Value::as_str).ok_or_else(invalid)?;
+                let port = n.get("port").and_then(Value::as_u64).map(|p| u16::try_from(p).map_err(|_| invalid())).transpose()?;
+                node(host, port, n.get("weight").and_then(Value::as_i64))
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+// endregion: ApisixUpstream
+
+// region: ApisixConsumer
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApisixConsumerSpec {
+    pub auth_parameter: CrdAuthParameter,
+}
+
+impl CrdSpec for ApisixConsumerSpec {
+    const KIND: &'static str = "ApisixConsumer";
+}
+
+/// Credentials of the Consumer, only `keyAuth` is converted, other methods are kept as is
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrdAuthParameter {
+    pub key_auth: Option<CrdKeyAuth>,
+    #[serde(flatten)]
+    pub others: HashMap<String, Value>,
+}
+
+/// Key given inline (`value`) or read from a Kubernetes Secret (`secretRef`), the latter has no Admin API equivalent
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrdKeyAuth {
+    pub value: Option<CrdKeyAuthValue>,
+    pub secret_ref: Option<CrdSecretRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdSecretRef {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrdKeyAuthValue {
+    pub key: String,
+}
+
+impl ApisixConsumerCrd {
+    /// Manifest named after the username, the `key-auth` key becomes the `keyAuth` parameter
+    /// Other plugins and Consumer Groups have no equivalent.
+    pub fn from_consumer(consumer: &ConsumerRequest) -> Result<Self> {
+        let name = consumer.username.clone().ok_or(ApisixClientError::InvalidRequest("Consumer without username".to_string()))?;
+        if consumer.group_id.is_some() {
+            return Err(unsupported(ApisixConsumerSpec::KIND, "group_id"));
+        }
+        let plugins = consumer.plugins.clone().unwrap_or_default();
+        if plugins != (Plugins { key_auth: plugins.key_auth.clone(), ..Plugins::default() }) {
+            return Err(unsupported(ApisixConsumerSpec::KIND, "plugin other than key-auth"));
+        }
+        let key_auth = match &plugins.key_auth {
+            Some(KeyAuth { key: Some(key), .. }) if plugins.key_auth == Some(KeyAuth { key: Some(key.clone()), ..KeyAuth::default() }) => {
+                Some(CrdKeyAuth { value: Some(CrdKeyAuthValue { key: key.clone() }), secret_ref: None })
+            },
+            Some(_) => return Err(unsupported(ApisixConsumerSpec::KIND, "key-auth without key or with other attributes")),
+            None => None,
+        };
+        let mut manifest = ApisixConsumerCrd::new(name, ApisixConsumerSpec {
+            auth_parameter: CrdAuthParameter { key_auth, others: HashMap::new() },
+        });
+        manifest.metadata.labels = consumer.labels.clone();
+        Ok(manifest)
+    }
+
+    /// Consumer with the manifest name as username, authentication methods other than `keyAuth` and
+    /// keys read from a Secret (`keyAuth.secretRef`) are rejected
+    pub fn to_consumer(&self) -> Result<ConsumerRequest> {
+        let auth = &self.spec.auth_parameter;
+        if let Some(method) = auth.others.keys().next() {
+            return Err(unsupported("ConsumerRequest", method));
+        }
+        let key_auth = match &auth.key_auth {
+            Some(CrdKeyAuth { value: Some(value), secret_ref: None }) => Some(KeyAuth { key: Some(value.key.clone()), ..KeyAuth::default() }),
+            Some(CrdKeyAuth { secret_ref: Some(_), .. }) => return Err(unsupported("ConsumerRequest", "keyAuth.secretRef")),
+            Some(_) => return Err(unsupported("ConsumerRequest", "keyAuth without value")),
+            None => None,
+        };
+        Ok(ConsumerRequest {
+            plugins: key_auth.map(|key_auth| Plugins { key_auth: Some(key_auth), ..Plugins::default() }),
+            username: Some(self.metadata.name.clone()),
+            group_id: None,
+            desc: None,
+            labels: self.metadata.labels.clone(),
+        })
+    }
+}
+// endregion: ApisixConsumer
+
+// region: ApisixPluginConfig
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApisixPluginConfigSpec {
+    pub plugins: Vec<CrdPlugin>,
+}
+
+impl CrdSpec for ApisixPluginConfigSpec {
+    const KIND: &'static str = "ApisixPluginConfig";
+}
+
+impl ApisixPluginConfigCrd {
+    pub fn from_plugins(name: impl Into<String>, plugins: &Plugins) -> Result<Self> {
+        Ok(ApisixPluginConfigCrd::new(name, ApisixPluginConfigSpec { plugins: plugins_to_crd(plugins)? }))
+    }
+
+    pub fn to_plugins(&self) -> Result<Plugins> {
+        plugins_from_crd(&self.spec.plugins)
+    }
+}
+// endregion: ApisixPluginConfig
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tracing_test::traced_test;
+    use crate::models::{KeyAuthBuilder, RouteBuilder, UpstreamBuilder};
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_route_crd_round_trip() {
+        let route = RouteBuilder::new()
+            .with_id("payments".to_string())
+            .with_uri("/payments/*".to_string())
+            .with_methods(vec!["GET".to_string()])
+            .with_upstream_id("payments-upstream".to_string())
+            .build().unwrap();
+        let manifest = ApisixRouteCrd::from_route(&route).unwrap().with_namespace("shop");
+        let yaml = manifest.to_yaml().unwrap();
+        assert!(yaml.starts_with("apiVersion: apisix.apache.org/v2\nkind: ApisixRoute\n"));
+        let parsed = ApisixRouteCrd::from_yaml_str(&yaml).unwrap();
+        let routes = parsed.to_routes().unwrap();
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].id.as_deref(), Some("payments"));
+        assert_eq!(routes[0].uri, route.uri);
+        assert_eq!(routes[0].upstream_id, route.upstream_id);
+
+        assert!(ApisixUpstreamCrd::from_yaml_str(&yaml).is_err());
+        let upstream = UpstreamBuilder::new().with_id("payments-upstream".to_string()).build().unwrap();
+        let manifests = objects_to_manifests(&[upstream.into(), route.clone().into()]).unwrap();
+        assert_eq!(manifests.matches("---\n").count(), 1);
+        let inline = RouteBuilder::new().with_uri("/a".to_string()).with_upstream(UpstreamBuilder::new().build().unwrap()).build().unwrap();
+        assert!(ApisixRouteCrd::from_route(&inline).is_err());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_upstream_crd_round_trip() {
+        let upstream = UpstreamBuilder::new()
+            .with_id("payments-upstream".to_string())
+            .with_nodes(json!({"payments.svc:8080": 2}))
+            .build().unwrap();
+        let manifest = ApisixUpstreamCrd::from_upstream(&upstream).unwrap();
+        let node = &manifest.spec.external_nodes.as_ref().unwrap()[0];
+        assert_eq!((node.name.as_str(), node.port, node.weight), ("payments.svc", Some(8080), Some(2)));
+        let converted = manifest.to_upstream().unwrap();
+        assert_eq!(converted.nodes, upstream.nodes);
+        assert_eq!(converted.type_field, upstream.type_field);
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms").unwrap(), 0.5);
+        assert_eq!(parse_duration("15s").unwrap(), 15.0);
+        assert_eq!(parse_duration("1m30s").unwrap(), 90.0);
+        assert_eq!(parse_duration("1h").unwrap(), 3600.0);
+        assert_eq!(parse_duration("1.5s").unwrap(), 1.5);
+        assert_eq!(parse_duration("0").unwrap(), 0.0);
+        for invalid in ["", "15", "s", "1d", "-1s"] {
+            assert!(parse_duration(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_consumer_and_plugin_config_crd() {
+        let key_auth = KeyAuthBuilder::new().with_key("secret".to_string()).build().unwrap();
+        let consumer = ConsumerRequest {
+            username: Some("jack".to_string()),
+            plugins: Some(Plugins { key_auth: Some(key_auth), ..Plugins::default() }),
+            ..ConsumerRequest::default()
+        };
+        let manifest = ApisixConsumerCrd::from_consumer(&consumer).unwrap();
+        assert_eq!(manifest.spec.auth_parameter.key_auth.as_ref().unwrap().value.as_ref().unwrap().key, "secret");
+        assert_eq!(manifest.to_consumer().unwrap().plugins, consumer.plugins);
+        let keyless = ConsumerRequest {
+            username: Some("jill".to_string()),
+            plugins: Some(Plugins { key_auth: Some(KeyAuth::default()), ..Plugins::default() }),
+            ..ConsumerRequest::default()
+        };
+        assert!(ApisixConsumerCrd::from_consumer(&keyless).is_err());
+
+        let yaml = "apiVersion: apisix.apache.org/v2\nkind: ApisixConsumer\nmetadata:\n  name: jack\nspec:\n  authParameter:\n    keyAuth:\n      secretRef:\n        name: jack-key\n";
+        let from_secret = ApisixConsumerCrd::from_yaml_str(yaml).unwrap();
+        assert_eq!(from_secret.spec.auth_parameter.key_auth.as_ref().unwrap().secret_ref.as_ref().unwrap().name, "jack-key");
+        let error = from_secret.to_consumer().unwrap_err();
+        assert!(matches!(&error, ApisixClientError::InvalidRequest(message) if message.contains("secretRef")), "{:?}", error);
+
+        let yaml = "apiVersion: apisix.apache.org/v2\nkind: ApisixPluginConfig\nmetadata:\n  name: auth\nspec:\n  plugins:\n    - name: key-auth\n      enable: true\n      config: {}\n    - name: echo\n      enable: false\n";
+        let plugin_config = ApisixPluginConfigCrd::from_yaml_str(yaml).unwrap();
+        assert!(plugin_config.to_plugins().unwrap().key_auth.is_some());
+        let unknown = ApisixPluginConfigCrd::new("x", ApisixPluginConfigSpec { plugins: vec![CrdPlugin { name: "echo".to_string(), enable: true, config: None }] });
+        assert!(unknown.to_plugins().is_err());
+    }
+}
+// endregion: tests
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use crate::error::ApisixClientError;
+use crate::models::{ApisixConsumer, ApisixConsumerGroup, ApisixCredential, ApisixPluginConfig, ApisixProto, ApisixRoute, ApisixService, ApisixSsl, ApisixUpstream};
+
+/// Objects carrying labels, used to select objects with a [LabelSelector]
+pub trait Labelled {
+    fn labels(&self) -> Option<&HashMap<String, String>>;
+}
+
+macro_rules! impl_labelled {
+    ($($model:ty),*) => {
+        $(impl Labelled for $model {
+            fn labels(&self) -> Option<&HashMap<String, String>> {
+                self.labels.as_ref()
+            }
+        })*
+    };
+}
+
+impl_labelled!(ApisixRoute, ApisixService, ApisixUpstream, ApisixConsumer, ApisixConsumerGroup,
+    ApisixSsl, ApisixPluginConfig, ApisixCredential, ApisixProto);
+
+/// A single requirement of a [LabelSelector]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum LabelRequirement {
+    /// `key=value`
+    Equals(String, String),
+    /// `key!=value`, also matches objects without the label
+    NotEquals(String, String),
+    /// `key`, the label is present whatever its value
+    Exists(String),
+}
+
+impl LabelRequirement {
+    fn matches(&self, labels: Option<&HashMap<String, String>>) -> bool {
+        let get = |key: &str| labels.and_then(|l| l.get(key));
+        match self {
+            LabelRequirement::Equals(key, value) => get(key) == Some(value),
+            LabelRequirement::NotEquals(key, value) => get(key) != Some(value),
+            LabelRequirement::Exists(key) => get(key).is_some(),
+        }
+    }
+}
+
+/// Comma separated list of label requirements, all of them must match
+/// Example: `env=prod,team=payments`, `env!=dev`, `owner`
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct LabelSelector {
+    pub requirements: Vec<LabelRequirement>,
+}
+
+impl LabelSelector {
+    pub fn new() -> Self {
+        LabelSelector::default()
+    }
+
+    /// Requires the label `key` to equal `value`
+    pub fn with_eq(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.requirements.push(LabelRequirement::Equals(key.into(), value.into()));
+        self
+    }
+
+    /// Requires the label `key` to differ from `value`
+    pub fn with_ne(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.requirements.push(LabelRequirement::NotEquals(key.into(), value.into()));
+        self
+    }
+
+    /// Requires the label `key` to be present
+    pub fn with_exists(mut self, key: impl Into<String>) -> Self {
+        self.requirements.push(LabelRequirement::Exists(key.into()));
+        self
+    }
+
+    /// True when the labels satisfy all the requirements, an empty selector matches everything
+    pub fn matches(&self, labels: Option<&HashMap<String, String>>) -> bool {
+        self.requirements.iter().all(|r| r.matches(labels))
+    }
+
+    /// Label filter understood by the Admin API (`key:value`), from the first equality requirement
+    /// Apisix filters on a single label, the other requirements are checked by [LabelSelector::matches]
+    pub fn server_filter(&self) -> Option<String> {
+        self.requirements.iter().find_map(|r| match r {
+            LabelRequirement::Equals(key, value) => Some(format!("{}:{}", key, value)),
+            _ => None,
+        })
+    }
+}
+
+impl FromStr for LabelSelector {
+    type Err = ApisixClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut selector = LabelSelector::new();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let requirement = if let Some((key, value)) = part.split_once("!=") {
+                LabelRequirement::NotEquals(key.trim().to_string(), value.trim().to_string())
+            } else if let Some((key, value)) = part.split_once('=') {
+                LabelRequirement::Equals(key.trim().to_string(), value.trim().to_string())
+            } else {
+                LabelRequirement::Exists(part.to_string())
+            };
+            match &requirement {
+                LabelRequirement::Equals(key, _) | LabelRequirement::NotEquals(key, _) if key.is_empty() => {
+                    return Err(ApisixClientError::InvalidRequest(format!("invalid label selector: {}", part)));
+                },
+                _ => selector.requirements.push(requirement),
+            }
+        }
+        Ok(selector)
+    }
+}
+
+impl fmt::Display for LabelSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.requirements.iter().map(|r| match r {
+            LabelRequirement::Equals(key, value) => format!("{}={}", key, value),
+            LabelRequirement::NotEquals(key, value) => format!("{}!={}", key, value),
+            LabelRequirement::Exists(key) => key.clone(),
+        }).collect();
+        write!(f, "{}", parts.join(","))
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_label_selector() {
+        let selector: LabelSelector = "env=prod, team=payments,canary!=true,owner".parse().unwrap();
+        assert_eq!(selector.requirements.len(), 4);
+        assert_eq!(selector.to_string(), "env=prod,team=payments,canary!=true,owner");
+        assert_eq!(selector.server_filter().unwrap(), "env:prod");
+
+        let mut labels = HashMap::from([
+            ("env".to_string(), "prod".to_string()),
+            ("team".to_string(), "payments".to_string()),
+            ("owner".to_string(), "ops".to_string()),
+        ]);
+        assert!(selector.matches(Some(&labels)));
+        labels.insert("canary".to_string(), "true".to_string());
+        assert!(!selector.matches(Some(&labels)));
+        assert!(!selector.matches(None));
+        assert!(LabelSelector::new().matches(None));
+        assert!("=prod".parse::<LabelSelector>().is_err());
+    }
+}
+// endregion: tests
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::error::ApisixClientError;
+use crate::{Result};
+
+/// Default page size used when walking all the pages of a collection
+pub const DEFAULT_PAGE_SIZE: i64 = 100;
+
+/// Builder pattern to create a ListQuery
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListQueryBuilder {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+    pub name: Option<String>,
+    pub label: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl ListQueryBuilder {
+    pub fn new() -> Self {
+        ListQuery::default().into()
+    }
+
+    /// Page to fetch, starting at 1
+    pub fn with_page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of objects per page, between 10 and 500
+    pub fn with_page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Only returns the objects whose name contains the value
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only returns the objects having the label, `key` or `key:value`
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Only returns the Routes whose uri contains the value
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    pub fn build(self) -> Result<ListQuery> {
+        let query = ListQuery {
+            page: self.page,
+            page_size: self.page_size,
+            name: self.name,
+            label: self.label,
+            uri: self.uri,
+        };
+        query.check()?;
+        Ok(query)
+    }
+}
+
+/// Pagination and filtering of the list endpoints (Apisix 3.x)
+/// Pagination is only applied by Apisix when both `page` and `page_size` are set
+#[serde_with::skip_serializing_none]
+#[derive(Validate, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListQuery {
+    #[validate(range(min = 1))]
+    pub page: Option<i64>,
+    #[validate(range(min = 10, max = 500))]
+    pub page_size: Option<i64>,
+    pub name: Option<String>,
+    pub label: Option<String>,
+    pub uri: Option<String>,
+}
+
+impl ListQuery {
+    /// Checks the page (from 1) and the page size (10 to 500), the fields being public
+    /// this is also done before each list request
+    pub fn check(&self) -> Result<()> {
+        self.validate().map_err(|v| ApisixClientError::InvalidRequest(v.to_string()))
+    }
+
+    /// Query parameters of the list request
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            params.push(("page_size", page_size.to_string()));
+        }
+        if let Some(name) = &self.name {
+            params.push(("name", name.clone()));
+        }
+        if let Some(label) = &self.label {
+            params.push(("label", label.clone()));
+        }
+        if let Some(uri) = &self.uri {
+            params.push(("uri", uri.clone()));
+        }
+        params
+    }
+
+    /// Same filters on the specified page
+    pub fn with_page(&self, page: i64, page_size: i64) -> Self {
+        ListQuery {
+            page: Some(page),
+            page_size: Some(page_size),
+            ..self.clone()
+        }
+    }
+}
+
+impl From<ListQuery> for ListQueryBuilder {
+    fn from(query: ListQuery) -> Self {
+        ListQueryBuilder {
+            page: query.page,
+            page_size: query.page_size,
+            name: query.name,
+            label: query.label,
+            uri: query.uri,
+        }
+    }
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_list_query_params() {
+        let query = ListQueryBuilder::new()
+            .with_page(2)
+            .with_page_size(50)
+            .with_label("env:prod")
+            .build().unwrap();
+        assert_eq!(query.params(), vec![("page", "2".to_string()), ("page_size", "50".to_string()), ("label", "env:prod".to_string())]);
+        assert!(ListQueryBuilder::new().with_page_size(1000).build().is_err());
+        assert!(ListQueryBuilder::new().with_page(0).build().is_err());
+        let query = ListQuery { page_size: Some(5), ..ListQuery::default() };
+        assert!(matches!(query.check(), Err(ApisixClientError::InvalidRequest(_))));
+    }
+}
+// endregion: tests
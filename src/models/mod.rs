@@ -11,6 +11,31 @@ mod consumer_group_requests;
 mod consumer_group_responses;
 mod consumer_requests;
 mod consumer_responses;
+mod admin_ssl_requests;
+mod admin_ssl_responses;
+mod admin_global_rule_requests;
+mod admin_global_rule_responses;
+mod admin_plugin_config_requests;
+mod admin_plugin_config_responses;
+mod admin_stream_route_requests;
+mod admin_stream_route_responses;
+mod admin_credential_requests;
+mod admin_credential_responses;
+mod admin_secret_requests;
+mod admin_secret_responses;
+mod admin_plugin_metadata_requests;
+mod admin_plugin_metadata_responses;
+mod admin_proto_requests;
+mod admin_proto_responses;
+mod list_query;
+mod labels;
+mod admin_objects;
+mod desired_state;
+mod drift;
+mod backup;
+mod standalone;
+mod ingress_crds;
+mod openapi;
 
 pub use admin_upstream_requests::*;
 pub use admin_upstream_responses::*;
@@ -22,6 +47,31 @@ pub use consumer_group_requests::*;
 pub use consumer_group_responses::*;
 pub use consumer_requests::*;
 pub use consumer_responses::*;
+pub use admin_ssl_requests::*;
+pub use admin_ssl_responses::*;
+pub use admin_global_rule_requests::*;
+pub use admin_global_rule_responses::*;
+pub use admin_plugin_config_requests::*;
+pub use admin_plugin_config_responses::*;
+pub use admin_stream_route_requests::*;
+pub use admin_stream_route_responses::*;
+pub use admin_credential_requests::*;
+pub use admin_credential_responses::*;
+pub use admin_secret_requests::*;
+pub use admin_secret_responses::*;
+pub use admin_plugin_metadata_requests::*;
+pub use admin_plugin_metadata_responses::*;
+pub use admin_proto_requests::*;
+pub use admin_proto_responses::*;
+pub use list_query::*;
+pub use labels::*;
+pub use admin_objects::*;
+pub use desired_state::*;
+pub use drift::*;
+pub use backup::*;
+pub use standalone::*;
+pub use ingress_crds::*;
+pub use openapi::*;
 pub use plugins::*;
 use rand::distr::Alphanumeric;
 
@@ -0,0 +1,365 @@
+use std::collections::HashSet;
+use std::path::Path;
+use serde_json::{json, Map, Value};
+use tracing::warn;
+use crate::error::ApisixClientError;
+use crate::models::{AdminObject, Plugins, ResourceKind, RouteBuilder, RouteRequest, UpstreamRequest};
+use crate::Result;
+
+/// Prefix of the OpenAPI extensions holding a plugin configuration, e.g. `x-apisix-limit-count`
+pub const OPENAPI_PLUGIN_EXTENSION_PREFIX: &str = "x-apisix-";
+
+const OPERATIONS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+/// Apisix Route ids are limited to 64 characters
+const MAX_ID_LENGTH: usize = 64;
+/// Route attributes exported as `x-apisix-<attribute>` by the Apisix Dashboard, they are not plugins
+const ROUTE_EXTENSIONS: [&str; 19] = [
+    "id", "name", "desc", "uri", "uris", "methods", "host", "hosts", "remote_addr", "remote_addrs", "vars",
+    "priority", "status", "labels", "enable_websocket", "filter_func", "script", "service_id", "upstream_id",
+];
+
+/// Generates one Route per operation of an OpenAPI 3.0/3.1 document (JSON or YAML)
+///
+/// * path templates become uri parameters, `/users/{id}` gives `/users/:id`, which requires the
+///   `radixtree_uri_with_parameter` router; with [OpenApiImporter::with_wildcard_paths] the uri stops
+///   at the first template instead, `/users/*`
+/// * the method of the operation becomes the only method of the Route
+/// * `x-apisix-<plugin>` extensions and the plugins of the `x-apisix-plugins` extension of the document,
+///   the path and the operation become plugins, the most specific one wins
+/// * the `x-apisix-upstream` extension becomes the Upstream of the Route, instead of the `upstream_id`;
+///   the other Route attributes exported by the Apisix Dashboard, e.g. `x-apisix-labels`, are skipped
+/// * ids longer than 64 characters are cut and suffixed with a hash of the full id, two operations
+///   with the same id are an error
+/// * the first supported security requirement picks the authentication plugin: an `apiKey` in a header
+///   or a query gives `key-auth`, an `http` `bearer` scheme gives `jwt-auth`
+#[derive(Default, Debug, Clone)]
+pub struct OpenApiImporter {
+    pub id_prefix: Option<String>,
+    pub base_path: Option<String>,
+    pub upstream_id: Option<String>,
+    pub service_id: Option<String>,
+    pub wildcard_paths: bool,
+}
+
+impl OpenApiImporter {
+    pub fn new() -> Self {
+        OpenApiImporter::default()
+    }
+
+    /// Prefix of the Route ids, e.g. the name of the API
+    pub fn with_id_prefix(mut self, id_prefix: impl Into<String>) -> Self {
+        self.id_prefix = Some(id_prefix.into());
+        self
+    }
+
+    /// Path prepended to every uri, e.g. `/v1`
+    pub fn with_base_path(mut self, base_path: impl Into<String>) -> Self {
+        self.base_path = Some(base_path.into());
+        self
+    }
+
+    pub fn with_upstream_id(mut self, upstream_id: impl Into<String>) -> Self {
+        self.upstream_id = Some(upstream_id.into());
+        self
+    }
+
+    pub fn with_service_id(mut self, service_id: impl Into<String>) -> Self {
+        self.service_id = Some(service_id.into());
+        self
+    }
+
+    pub fn with_wildcard_paths(mut self, wildcard_paths: bool) -> Self {
+        self.wildcard_paths = wildcard_paths;
+        self
+    }
+
+    pub fn import_file(&self, path: impl AsRef<Path>) -> Result<Vec<RouteRequest>> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("unable to read {}: {}", path.display(), e)))?;
+        self.import_str(&content)
+    }
+
+    /// Routes of the document, JSON being a subset of YAML both are accepted
+    pub fn import_str(&self, document: &str) -> Result<Vec<RouteRequest>> {
+        let document: Value = serde_yaml::from_str(document)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("invalid OpenAPI document: {}", e)))?;
+        self.import(&document)
+    }
+
+    pub fn import(&self, document: &Value) -> Result<Vec<RouteRequest>> {
+        let version = document.get("openapi").and_then(Value::as_str).unwrap_or_default();
+        if !version.starts_with("3.") {
+            return Err(ApisixClientError::InvalidRequest(format!("unsupported OpenAPI version: {:?}, 3.x expected", version)));
+        }
+        let schemes = document.pointer("/components/securitySchemes").cloned().unwrap_or(Value::Null);
+        let mut routes = vec![];
+        let mut ids = HashSet::new();
+        for (path, item) in document.get("paths").and_then(Value::as_object).into_iter().flatten() {
+            for method in OPERATIONS {
+                let Some(operation) = item.get(method).filter(|o| o.is_object()) else { continue };
+                let mut extensions = Extensions::default();
+                for object in [document, item, operation] {
+                    extensions.merge(object)
+                        .map_err(|e| ApisixClientError::InvalidRequest(format!("{} {}: {}", method, path, e)))?;
+                }
+                let mut configs = extensions.plugins;
+                let security = operation.get("security").or(document.get("security"));
+                if let Some((name, config)) = security.and_then(|s| auth_plugin(s, &schemes)) {
+                    configs.entry(name).or_insert(config);
+                }
+                let operation_id = operation.get("operationId").and_then(Value::as_str);
+                let id = self.route_id(operation_id.map(str::to_string).unwrap_or(format!("{}-{}", method, path)));
+                if !ids.insert(id.clone()) {
+                    return Err(ApisixClientError::InvalidRequest(format!("{} {}: duplicate route id {}", method, path, id)));
+                }
+                let mut builder = RouteBuilder::new()
+                    .with_id(id)
+                    .with_uri(format!("{}{}", self.base_path.as_deref().unwrap_or_default().trim_end_matches('/'), self.uri(path)))
+                    .with_methods(vec![method.to_uppercase()]);
+                if let Some(operation_id) = operation_id {
+                    builder = builder.with_name(operation_id.to_string());
+                }
+                if let Some(summary) = operation.get("summary").and_then(Value::as_str) {
+                    builder = builder.with_desc(summary.to_string());
+                }
+                if !configs.is_empty() {
+                    let plugins = Plugins::from_configs(configs)
+                        .map_err(|e| ApisixClientError::InvalidRequest(format!("{} {}: {}", method, path, e)))?;
+                    builder = builder.with_plugins(plugins);
+                }
+                match (&extensions.upstream, &self.upstream_id) {
+                    (Some(upstream), _) => builder = builder.with_upstream(upstream.clone()),
+                    (None, Some(upstream_id)) => builder = builder.with_upstream_id(upstream_id.clone()),
+                    (None, None) => {},
+                }
+                if let Some(service_id) = &self.service_id {
+                    builder = builder.with_service_id(service_id.clone());
+                }
+                routes.push(builder.build()?);
+            }
+        }
+        Ok(routes)
+    }
+
+    /// Uri of a path template, see [OpenApiImporter]
+    fn uri(&self, path: &str) -> String {
+        let mut segments = vec![];
+        for segment in path.split('/') {
+            let parameter = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}'));
+            match parameter {
+                Some(name) if !self.wildcard_paths && !name.contains(['{', '}']) => segments.push(format!(":{}", name)),
+                _ if segment.contains('{') => {
+                    segments.push("*".to_string());
+                    break;
+                },
+                _ => segments.push(segment.to_string()),
+            }
+        }
+        segments.join("/")
+    }
+
+    /// Prefixed id restricted to the characters allowed by Apisix, a longer id than [MAX_ID_LENGTH]
+    /// is cut and suffixed with the hash of the full id to stay unique
+    fn route_id(&self, id: String) -> String {
+        let id = match &self.id_prefix {
+            Some(prefix) => format!("{}-{}", prefix, id),
+            None => id,
+        };
+        let id: String = id.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '-' })
+            .collect();
+        let id = id.trim_matches('-');
+        if id.len() <= MAX_ID_LENGTH {
+            return id.to_string();
+        }
+        let suffix = format!("-{:08x}", fnv1a(id));
+        format!("{}{}", id[..MAX_ID_LENGTH - suffix.len()].trim_end_matches('-'), suffix)
+    }
+}
+
+/// `x-apisix-*` extensions of the OpenAPI objects, see [OpenApiImporter]
+#[derive(Default)]
+struct Extensions {
+    plugins: Map<String, Value>,
+    upstream: Option<UpstreamRequest>,
+}
+
+impl Extensions {
+    /// Adds the extensions of a more specific object, within an object the `x-apisix-<plugin>`
+    /// extensions win over the plugins of `x-apisix-plugins`
+    fn merge(&mut self, object: &Value) -> Result<()> {
+        let plugins_key = format!("{}plugins", OPENAPI_PLUGIN_EXTENSION_PREFIX);
+        match object.get(&plugins_key) {
+            Some(Value::Object(plugins)) => self.plugins.extend(plugins.clone()),
+            Some(_) => return Err(ApisixClientError::InvalidRequest(format!("{} is not an object", plugins_key))),
+            None => {},
+        }
+        for (key, value) in object.as_object().into_iter().flatten() {
+            match key.strip_prefix(OPENAPI_PLUGIN_EXTENSION_PREFIX) {
+                None | Some("plugins") => {},
+                Some("upstream") => match AdminObject::from_value(ResourceKind::upstreams, value.clone())? {
+                    AdminObject::Upstream(upstream) => self.upstream = Some(upstream),
+                    _ => return Err(ApisixClientError::InvalidRequest(format!("invalid {}", key))),
+                },
+                Some(name) if ROUTE_EXTENSIONS.contains(&name) => warn!("extension {} is not supported, skipped", key),
+                Some(name) => {
+                    self.plugins.insert(name.to_string(), value.clone());
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 32 bits FNV-1a hash, stable across builds unlike the std hashers
+fn fnv1a(value: &str) -> u32 {
+    value.bytes().fold(0x811c9dc5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193))
+}
+
+/// Authentication plugin of the first supported security requirement, `None` when the requirements
+/// allow anonymous access (`{}`) or none is supported
+fn auth_plugin(security: &Value, schemes: &Value) -> Option<(String, Value)> {
+    for requirement in security.as_array().into_iter().flatten() {
+        let Some(names) = requirement.as_object() else { continue };
+        if names.is_empty() {
+            return None;
+        }
+        for name in names.keys() {
+            let scheme = schemes.get(name).unwrap_or(&Value::Null);
+            let kind = scheme.get("type").and_then(Value::as_str);
+            let location = scheme.get("in").and_then(Value::as_str);
+            let parameter = scheme.get("name").cloned().unwrap_or(Value::Null);
+            let http_scheme = scheme.get("scheme").and_then(Value::as_str).map(str::to_lowercase);
+            match (kind, location, http_scheme.as_deref()) {
+                (Some("apiKey"), Some("header"), _) => return Some(("key-auth".to_string(), json!({"header": parameter}))),
+                (Some("apiKey"), Some("query"), _) => return Some(("key-auth".to_string(), json!({"query": parameter}))),
+                (Some("http"), _, Some("bearer")) => return Some(("jwt-auth".to_string(), json!({}))),
+                _ => warn!("security scheme {} is not supported, skipped", name),
+            }
+        }
+    }
+    None
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    const PETSTORE: &str = r#"
+openapi: 3.0.3
+info: { title: Petstore, version: "1.0" }
+x-apisix-limit-count: { count: 100, time_window: 60 }
+security:
+  - apiKey: []
+components:
+  securitySchemes:
+    apiKey: { type: apiKey, in: header, name: X-API-KEY }
+    bearer: { type: http, scheme: bearer, bearerFormat: JWT }
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List all pets
+      security: [{}]
+    post:
+      security:
+        - bearer: []
+  /pets/{petId}/photos/{photo}.jpg:
+    get:
+      operationId: getPetPhoto
+      x-apisix-limit-count: { count: 5, time_window: 60 }
+"#;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_import_openapi() {
+        let routes = OpenApiImporter::new().with_id_prefix("petstore").with_upstream_id("pets").import_str(PETSTORE).unwrap();
+        assert_eq!(routes.len(), 3);
+
+        let list = &routes[0];
+        assert_eq!(list.id.as_deref(), Some("petstore-listPets"));
+        assert_eq!(list.uri.as_deref(), Some("/pets"));
+        assert_eq!(list.methods, Some(vec!["GET".to_string()]));
+        assert_eq!(list.desc.as_deref(), Some("List all pets"));
+        let plugins = list.plugins.as_ref().unwrap();
+        assert!(plugins.key_auth.is_none());
+        assert_eq!(plugins.limit_count.as_ref().unwrap().count, Some(100));
+
+        let create = &routes[1];
+        assert_eq!(create.id.as_deref(), Some("petstore-post--pets"));
+        assert!(create.plugins.as_ref().unwrap().jwt_auth.is_some());
+
+        let photo = &routes[2];
+        assert_eq!(photo.uri.as_deref(), Some("/pets/:petId/photos/*"));
+        let plugins = photo.plugins.as_ref().unwrap();
+        assert_eq!(plugins.key_auth.as_ref().unwrap().header.as_deref(), Some("X-API-KEY"));
+        assert_eq!(plugins.limit_count.as_ref().unwrap().count, Some(5));
+        assert_eq!(photo.upstream_id.as_deref(), Some("pets"));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_import_openapi_options_and_errors() {
+        let document = r#"{"openapi": "3.1.0", "paths": {"/users/{id}": {"delete": {}}}}"#;
+        let routes = OpenApiImporter::new().with_wildcard_paths(true).with_base_path("/v1/").import_str(document).unwrap();
+        assert_eq!(routes[0].uri.as_deref(), Some("/v1/users/*"));
+        assert_eq!(routes[0].id.as_deref(), Some("delete--users--id"));
+
+        assert!(OpenApiImporter::new().import_str(r#"{"swagger": "2.0", "paths": {}}"#).is_err());
+        let unknown = r#"{"openapi": "3.0.0", "paths": {"/a": {"get": {"x-apisix-echo": {}}}}}"#;
+        assert!(OpenApiImporter::new().import_str(unknown).is_err());
+        let duplicate = r#"{"openapi": "3.0.0", "paths": {"/a": {"get": {"operationId": "op"}}, "/b": {"get": {"operationId": "op"}}}}"#;
+        assert!(OpenApiImporter::new().import_str(duplicate).is_err());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_import_openapi_long_ids() {
+        let long = "a".repeat(70);
+        let document = json!({"openapi": "3.0.0", "paths": {
+            "/a": {"get": {"operationId": format!("{}1", long)}},
+            "/b": {"get": {"operationId": format!("{}2", long)}},
+        }});
+        let routes = OpenApiImporter::new().import(&document).unwrap();
+        let ids: Vec<&str> = routes.iter().map(|r| r.id.as_deref().unwrap()).collect();
+        assert!(ids.iter().all(|id| id.len() == MAX_ID_LENGTH && id.starts_with(&long[..50])));
+        assert_ne!(ids[0], ids[1]);
+        assert_eq!(OpenApiImporter::new().import(&document).unwrap()[0].id.as_deref(), Some(ids[0]));
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_import_openapi_dashboard_extensions() {
+        let document = r#"
+openapi: 3.0.0
+x-apisix-plugins:
+  limit-count: { count: 100, time_window: 60 }
+paths:
+  /a:
+    get:
+      x-apisix-plugins:
+        limit-count: { count: 10, time_window: 60 }
+      x-apisix-limit-count: { count: 5, time_window: 60 }
+      x-apisix-upstream: { type: roundrobin, nodes: { "127.0.0.1:80": 1 } }
+      x-apisix-labels: { team: a }
+      x-apisix-status: 1
+    post: {}
+"#;
+        let routes = OpenApiImporter::new().with_upstream_id("u1").import_str(document).unwrap();
+        let get = &routes[0];
+        assert_eq!(get.plugins.as_ref().unwrap().limit_count.as_ref().unwrap().count, Some(5));
+        assert!(get.upstream.is_some());
+        assert!(get.upstream_id.is_none());
+        assert!(get.labels.is_none());
+        let post = &routes[1];
+        assert_eq!(post.plugins.as_ref().unwrap().limit_count.as_ref().unwrap().count, Some(100));
+        assert_eq!(post.upstream_id.as_deref(), Some("u1"));
+
+        let invalid = r#"{"openapi": "3.0.0", "paths": {"/a": {"get": {"x-apisix-plugins": []}}}}"#;
+        assert!(OpenApiImporter::new().import_str(invalid).is_err());
+    }
+}
+// endregion: tests
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use validator::Validate;
+use crate::error::ApisixClientError;
+use crate::{Result};
+use crate::models::Plugin;
+
+/// Builder pattern to create a GrpcTranscode
+#[serde_with::skip_serializing_none]
+#[derive(Validate, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrpcTranscodeBuilder {
+    #[validate(length(min = 1))]
+    pub proto_id: Option<String>,
+    #[validate(length(min = 1))]
+    pub service: Option<String>,
+    #[validate(length(min = 1))]
+    pub method: Option<String>,
+    pub deadline: Option<i64>,
+    pub pb_option: Option<Vec<GrpcTranscodePbOption>>,
+    pub show_status_in_body: Option<bool>,
+    pub status_detail_type: Option<String>,
+}
+
+impl GrpcTranscodeBuilder {
+    pub fn new() -> Self {
+        GrpcTranscode::default().into()
+    }
+
+    /// Id of the proto content, see [crate::models::ProtoRequest]
+    pub fn with_proto_id(mut self, proto_id: impl Into<String>) -> Self {
+        self.proto_id = Some(proto_id.into());
+        self
+    }
+
+    /// Name of the gRPC service, including the package
+    /// Example: "helloworld.Greeter"
+    pub fn with_service(mut self, service: impl Into<String>) -> Self {
+        self.service = Some(service.into());
+        self
+    }
+
+    /// Method name of the gRPC service
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    /// Deadline for the gRPC service in ms
+    pub fn with_deadline(mut self, deadline: i64) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Protobuf options for the encoding and decoding of the messages
+    pub fn with_pb_option(mut self, pb_option: Vec<GrpcTranscodePbOption>) -> Self {
+        self.pb_option = Some(pb_option);
+        self
+    }
+
+    /// When true, adds the grpc-status-details-bin to the response body
+    pub fn with_show_status_in_body(mut self, show_status_in_body: bool) -> Self {
+        self.show_status_in_body = Some(show_status_in_body);
+        self
+    }
+
+    /// Message type of the details part in the error status
+    pub fn with_status_detail_type(mut self, status_detail_type: impl Into<String>) -> Self {
+        self.status_detail_type = Some(status_detail_type.into());
+        self
+    }
+
+    pub fn build(self) -> Result<GrpcTranscode> {
+        self.validate().map_err(|v| ApisixClientError::PluginConfigException(v.to_string()))?;
+        if self.proto_id.is_none() || self.service.is_none() || self.method.is_none() {
+            return Err(ApisixClientError::PluginConfigException("grpc-transcode requires proto_id, service and method".to_string()));
+        }
+        Ok(GrpcTranscode {
+            proto_id: self.proto_id,
+            service: self.service,
+            method: self.method,
+            deadline: self.deadline,
+            pb_option: self.pb_option,
+            show_status_in_body: self.show_status_in_body,
+            status_detail_type: self.status_detail_type,
+        })
+    }
+}
+
+/// The grpc-transcode Plugin converts between HTTP and gRPC requests.
+/// [Documentation](https://apisix.apache.org/docs/apisix/plugins/grpc-transcode/)
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrpcTranscode {
+    pub proto_id: Option<String>,
+    pub service: Option<String>,
+    pub method: Option<String>,
+    pub deadline: Option<i64>,
+    pub pb_option: Option<Vec<GrpcTranscodePbOption>>,
+    pub show_status_in_body: Option<bool>,
+    pub status_detail_type: Option<String>,
+}
+
+impl From<GrpcTranscode> for GrpcTranscodeBuilder {
+    fn from(item: GrpcTranscode) -> Self {
+        GrpcTranscodeBuilder {
+            proto_id: item.proto_id,
+            service: item.service,
+            method: item.method,
+            deadline: item.deadline,
+            pb_option: item.pb_option,
+            show_status_in_body: item.show_status_in_body,
+            status_detail_type: item.status_detail_type,
+        }
+    }
+}
+
+impl Plugin for GrpcTranscode {}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[allow(non_camel_case_types)]
+#[strum(ascii_case_insensitive)]
+#[non_exhaustive]
+pub enum GrpcTranscodePbOption {
+    enum_as_name,
+    enum_as_value,
+    int64_as_number,
+    int64_as_string,
+    int64_as_hexstring,
+    auto_default_values,
+    no_default_values,
+    use_default_values,
+    use_default_metatable,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use crate::models::ProtoBuilder;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_grpc_transcode_with_proto() {
+        let proto = ProtoBuilder::new().with_content("syntax = \"proto3\";").build().unwrap();
+        let transcode = GrpcTranscodeBuilder::new()
+            .with_proto_id(proto.id.clone().unwrap())
+            .with_service("helloworld.Greeter")
+            .with_method("SayHello")
+            .with_pb_option(vec![GrpcTranscodePbOption::int64_as_string])
+            .build().unwrap();
+        assert_eq!(transcode.proto_id, proto.id);
+        assert!(GrpcTranscodeBuilder::new().with_service("helloworld.Greeter").build().is_err());
+    }
+}
+// endregion: tests
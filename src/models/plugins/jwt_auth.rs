@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use crate::{Result};
+use crate::models::Plugin;
+
+/// Builder pattern to create a JwtAuth
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtAuthBuilder {
+    pub header: Option<String>,
+    pub query: Option<String>,
+    pub cookie: Option<String>,
+    pub hide_credentials: Option<bool>,
+    pub key: Option<String>,
+    pub secret: Option<String>,
+    pub public_key: Option<String>,
+    pub algorithm: Option<JwtAlgorithm>,
+    pub exp: Option<i64>,
+}
+
+impl JwtAuthBuilder {
+    pub fn new() -> Self {
+        JwtAuth::default().into()
+    }
+
+    /// The header to get the token from, defaults to `authorization`
+    pub fn with_header(mut self, header: impl Into<String>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// The query string to get the token from, lower priority than header
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// The cookie to get the token from, lower priority than query
+    pub fn with_cookie(mut self, cookie: impl Into<String>) -> Self {
+        self.cookie = Some(cookie.into());
+        self
+    }
+
+    /// Removes the token before proxying to the Upstream when true
+    pub fn with_hide_credentials(mut self, hide_credentials: bool) -> Self {
+        self.hide_credentials = Some(hide_credentials);
+        self
+    }
+
+    /// The key of the Consumer, only used when the plugin is configured on a Consumer
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// The HMAC secret of the Consumer, used with the HS* algorithms
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// The public key of the Consumer, used with the RS* and ES* algorithms
+    pub fn with_public_key(mut self, public_key: impl Into<String>) -> Self {
+        self.public_key = Some(public_key.into());
+        self
+    }
+
+    pub fn with_algorithm(mut self, algorithm: JwtAlgorithm) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Expiry of the token in seconds
+    pub fn with_exp(mut self, exp: i64) -> Self {
+        self.exp = Some(exp);
+        self
+    }
+
+    pub fn build(self) -> Result<JwtAuth> {
+        Ok(JwtAuth {
+            header: self.header,
+            query: self.query,
+            cookie: self.cookie,
+            hide_credentials: self.hide_credentials,
+            key: self.key,
+            secret: self.secret,
+            public_key: self.public_key,
+            algorithm: self.algorithm,
+            exp: self.exp,
+        })
+    }
+}
+
+/// The jwt-auth Plugin authenticates requests carrying a JSON Web Token.
+/// On a Route it tells where to find the token, on a Consumer it holds the key and signing material.
+/// [Documentation](https://apisix.apache.org/docs/apisix/plugins/jwt-auth/)
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtAuth {
+    pub header: Option<String>,
+    pub query: Option<String>,
+    pub cookie: Option<String>,
+    pub hide_credentials: Option<bool>,
+    pub key: Option<String>,
+    pub secret: Option<String>,
+    pub public_key: Option<String>,
+    pub algorithm: Option<JwtAlgorithm>,
+    pub exp: Option<i64>,
+}
+
+impl From<JwtAuth> for JwtAuthBuilder {
+    fn from(item: JwtAuth) -> Self {
+        JwtAuthBuilder {
+            header: item.header,
+            query: item.query,
+            cookie: item.cookie,
+            hide_credentials: item.hide_credentials,
+            key: item.key,
+            secret: item.secret,
+            public_key: item.public_key,
+            algorithm: item.algorithm,
+            exp: item.exp,
+        }
+    }
+}
+impl Plugin for JwtAuth {}
+
+/// Signing algorithms supported by the jwt-auth Plugin
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[non_exhaustive]
+pub enum JwtAlgorithm {
+    HS256,
+    HS512,
+    RS256,
+    ES256,
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_jwt_auth_response() {
+        let jwt = r#"
+        {
+            "key": "user-key",
+            "secret": "my-secret-key",
+            "algorithm": "HS256"
+        }"#;
+        let jwt: JwtAuth = serde_json::from_str(jwt).unwrap();
+        assert_eq!(jwt.key.unwrap(), "user-key");
+        assert_eq!(jwt.algorithm.unwrap(), JwtAlgorithm::HS256);
+        let route_side = JwtAuthBuilder::new().with_header("x-token").build().unwrap();
+        assert_eq!(serde_json::to_string(&route_side).unwrap(), r#"{"header":"x-token"}"#);
+    }
+}
+// endregion: tests
@@ -9,6 +9,7 @@ pub struct KeyAuthBuilder {
     pub hide_credentials: Option<bool>,
     pub query: Option<String>,
     pub header: Option<String>,
+    pub key: Option<String>,
 }
 
 impl KeyAuthBuilder {
@@ -36,11 +37,18 @@ impl KeyAuthBuilder {
         self
     }
 
+    /// The key of the Consumer, only used when the plugin is configured on a Consumer
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
     pub fn build(self) -> Result<KeyAuth> {
         Ok(KeyAuth {
             hide_credentials: self.hide_credentials,
             query: self.query,
             header: self.header,
+            key: self.key,
         })
     }
 }
@@ -55,6 +63,7 @@ pub struct KeyAuth {
     pub hide_credentials: Option<bool>,
     pub query: Option<String>,
     pub header: Option<String>,
+    pub key: Option<String>,
 }
 
 impl From<KeyAuth> for KeyAuthBuilder {
@@ -63,6 +72,7 @@ impl From<KeyAuth> for KeyAuthBuilder {
             hide_credentials: item.hide_credentials,
             query: item.query,
             header: item.header,
+            key: item.key,
         }
     }
 }
@@ -1,15 +1,24 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::error::ApisixClientError;
+use crate::Result;
 
 mod key_auth;
 mod proxy_rewrite;
 mod limit_count;
 mod consumer_restriction;
+mod mqtt_proxy;
+mod grpc_transcode;
+mod jwt_auth;
 //mod authz_keycloak;
 
 pub use key_auth::*;
 pub use proxy_rewrite::*;
 pub use limit_count::*;
 pub use consumer_restriction::*;
+pub use mqtt_proxy::*;
+pub use grpc_transcode::*;
+pub use jwt_auth::*;
 
 // region: common
 /// Plugins that are executed during the request/response cycle.
@@ -24,6 +33,33 @@ pub struct Plugins {
     pub limit_count: Option<LimitCount>,
     #[serde(rename = "consumer-restriction")]
     pub consumer_restriction: Option<ConsumerRestriction>,
+    #[serde(rename = "grpc-transcode")]
+    pub grpc_transcode: Option<GrpcTranscode>,
+    #[serde(rename = "jwt-auth")]
+    pub jwt_auth: Option<JwtAuth>,
+}
+
+impl Plugins {
+    /// Plugins from their configurations keyed by plugin name, e.g. `limit-count`
+    /// Plugins not modelled by this struct are rejected rather than dropped.
+    pub fn from_configs(configs: Map<String, Value>) -> Result<Self> {
+        let plugins: Plugins = serde_json::from_value(Value::Object(configs.clone()))
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("invalid plugin configuration: {}", e)))?;
+        let known = serde_json::to_value(&plugins).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))?;
+        let unsupported: Vec<&String> = configs.keys().filter(|name| known.get(name.as_str()).is_none()).collect();
+        if !unsupported.is_empty() {
+            return Err(ApisixClientError::InvalidRequest(format!("unsupported plugins: {:?}", unsupported)));
+        }
+        Ok(plugins)
+    }
+}
+
+/// Plugins that are executed by the stream (L4) subsystem, see [crate::models::StreamRouteRequest]
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamPlugins {
+    #[serde(rename = "mqtt-proxy")]
+    pub mqtt_proxy: Option<MqttProxy>,
 }
 
 pub trait Plugin {}
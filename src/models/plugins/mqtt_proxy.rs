@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use crate::error::ApisixClientError;
+use crate::{Result};
+use crate::models::Plugin;
+
+/// Builder pattern to create a MqttProxy
+#[serde_with::skip_serializing_none]
+#[derive(Validate, Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MqttProxyBuilder {
+    #[validate(length(min = 1))]
+    pub protocol_name: Option<String>,
+    pub protocol_level: Option<i64>,
+}
+
+impl MqttProxyBuilder {
+    pub fn new() -> Self {
+        MqttProxy::default().into()
+    }
+
+    /// Name of the protocol, generally MQTT
+    pub fn with_protocol_name(mut self, protocol_name: impl Into<String>) -> Self {
+        self.protocol_name = Some(protocol_name.into());
+        self
+    }
+
+    /// Level of the protocol: 4 for MQTT 3.1.1, 5 for MQTT 5.0
+    pub fn with_protocol_level(mut self, protocol_level: i64) -> Self {
+        self.protocol_level = Some(protocol_level);
+        self
+    }
+
+    pub fn build(self) -> Result<MqttProxy> {
+        self.validate().map_err(|v| ApisixClientError::PluginConfigException(v.to_string()))?;
+        Ok(MqttProxy {
+            protocol_name: self.protocol_name,
+            protocol_level: self.protocol_level,
+        })
+    }
+}
+
+/// The mqtt-proxy Plugin is used for dynamic load balancing with client_id of MQTT.
+/// It only works in stream model.
+/// [Documentation](https://apisix.apache.org/docs/apisix/plugins/mqtt-proxy/)
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MqttProxy {
+    pub protocol_name: Option<String>,
+    pub protocol_level: Option<i64>,
+}
+
+impl From<MqttProxy> for MqttProxyBuilder {
+    fn from(item: MqttProxy) -> Self {
+        MqttProxyBuilder {
+            protocol_name: item.protocol_name,
+            protocol_level: item.protocol_level,
+        }
+    }
+}
+
+impl Plugin for MqttProxy {}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_parse_mqtt_proxy_response() {
+        let nodes = r#"
+        {
+            "protocol_name": "MQTT",
+            "protocol_level": 4
+        }"#;
+        let nodes: MqttProxy = serde_json::from_str(nodes).unwrap();
+        assert_eq!(nodes.protocol_name.unwrap(), "MQTT");
+        assert_eq!(nodes.protocol_level.unwrap(), 4);
+    }
+}
+// endregion: tests
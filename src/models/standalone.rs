@@ -0,0 +1,259 @@
+use std::path::Path;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::error::ApisixClientError;
+use crate::models::{sort_for_apply, AdminObject, DesiredState, ResourceKind};
+use crate::Result;
+
+/// Line closing a standalone configuration, Apisix ignores a file without it as partially written
+pub const STANDALONE_END_MARKER: &str = "#END";
+
+/// Configuration of Apisix running in standalone mode (`conf/apisix.yaml`)
+///
+/// Objects are kept as raw values, plugins and attributes not modelled by this crate included;
+/// [StandaloneConfig::objects] checks them against the request models.
+/// ```yaml
+/// upstreams:
+///   - id: 1
+///     nodes: { "127.0.0.1:1980": 1 }
+/// routes:
+///   - id: 1
+///     uri: /hello
+///     upstream_id: 1
+/// #END
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StandaloneConfig {
+    pub ssls: Option<Vec<Value>>,
+    pub protos: Option<Vec<Value>>,
+    pub upstreams: Option<Vec<Value>>,
+    pub services: Option<Vec<Value>>,
+    pub plugin_configs: Option<Vec<Value>>,
+    pub global_rules: Option<Vec<Value>>,
+    pub consumer_groups: Option<Vec<Value>>,
+    pub consumers: Option<Vec<Value>>,
+    pub routes: Option<Vec<Value>>,
+    pub stream_routes: Option<Vec<Value>>,
+    pub plugin_metadata: Option<Vec<Value>>,
+    pub plugins: Option<Vec<Value>>,
+}
+
+impl StandaloneConfig {
+    /// Kinds having a section in the configuration, Plugin metadata are identified by their plugin name
+    pub const KINDS: [ResourceKind; 11] = [
+        ResourceKind::plugin_metadata,
+        ResourceKind::ssls,
+        ResourceKind::protos,
+        ResourceKind::upstreams,
+        ResourceKind::services,
+        ResourceKind::plugin_configs,
+        ResourceKind::global_rules,
+        ResourceKind::consumer_groups,
+        ResourceKind::consumers,
+        ResourceKind::routes,
+        ResourceKind::stream_routes,
+    ];
+
+    /// Builds a configuration holding the objects, kinds without objects are left out
+    pub fn from_objects(objects: impl IntoIterator<Item = AdminObject>) -> Result<Self> {
+        let mut state = DesiredState::default();
+        objects.into_iter().for_each(|o| state.push(o));
+        state.try_into()
+    }
+
+    /// Raw objects of the kind, `None` when the kind has no section, see [StandaloneConfig::KINDS]
+    pub fn section(&self, kind: ResourceKind) -> Option<&Option<Vec<Value>>> {
+        match kind {
+            ResourceKind::ssls => Some(&self.ssls),
+            ResourceKind::protos => Some(&self.protos),
+            ResourceKind::upstreams => Some(&self.upstreams),
+            ResourceKind::services => Some(&self.services),
+            ResourceKind::plugin_configs => Some(&self.plugin_configs),
+            ResourceKind::global_rules => Some(&self.global_rules),
+            ResourceKind::consumer_groups => Some(&self.consumer_groups),
+            ResourceKind::consumers => Some(&self.consumers),
+            ResourceKind::routes => Some(&self.routes),
+            ResourceKind::stream_routes => Some(&self.stream_routes),
+            ResourceKind::plugin_metadata => Some(&self.plugin_metadata),
+            ResourceKind::secrets | ResourceKind::credentials => None,
+        }
+    }
+
+    pub fn section_mut(&mut self, kind: ResourceKind) -> Option<&mut Option<Vec<Value>>> {
+        match kind {
+            ResourceKind::ssls => Some(&mut self.ssls),
+            ResourceKind::protos => Some(&mut self.protos),
+            ResourceKind::upstreams => Some(&mut self.upstreams),
+            ResourceKind::services => Some(&mut self.services),
+            ResourceKind::plugin_configs => Some(&mut self.plugin_configs),
+            ResourceKind::global_rules => Some(&mut self.global_rules),
+            ResourceKind::consumer_groups => Some(&mut self.consumer_groups),
+            ResourceKind::consumers => Some(&mut self.consumers),
+            ResourceKind::routes => Some(&mut self.routes),
+            ResourceKind::stream_routes => Some(&mut self.stream_routes),
+            ResourceKind::plugin_metadata => Some(&mut self.plugin_metadata),
+            ResourceKind::secrets | ResourceKind::credentials => None,
+        }
+    }
+
+    /// Objects of the [ResourceKind::TYPED] kinds, in dependency order
+    ///
+    /// Fails when an object holds plugins or attributes that are not modelled by this crate,
+    /// see [AdminObject::from_value].
+    pub fn objects(&self) -> Result<Vec<AdminObject>> {
+        let mut objects = vec![];
+        for kind in ResourceKind::TYPED {
+            for value in self.section(kind).into_iter().flatten().flatten() {
+                objects.push(AdminObject::from_value(kind, value.clone())?);
+            }
+        }
+        sort_for_apply(&mut objects);
+        Ok(objects)
+    }
+
+    /// Renders the configuration followed by [STANDALONE_END_MARKER]
+    pub fn to_yaml(&self) -> Result<String> {
+        let yaml = serde_yaml::to_string(self).map_err(|e| ApisixClientError::InvalidRequest(e.to_string()))?;
+        Ok(format!("{}{}\n", yaml, STANDALONE_END_MARKER))
+    }
+
+    /// Parses a configuration, the last non blank line must be [STANDALONE_END_MARKER]
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        let ended = yaml.lines().rev().find(|l| !l.trim().is_empty()).is_some_and(|l| l.trim() == STANDALONE_END_MARKER);
+        if !ended {
+            return Err(ApisixClientError::InvalidRequest(format!("standalone configuration does not end with {}", STANDALONE_END_MARKER)));
+        }
+        // An empty document (only the marker) is an empty configuration
+        if yaml.lines().all(|l| l.trim().is_empty() || l.trim_start().starts_with('#')) {
+            return Ok(StandaloneConfig::default());
+        }
+        serde_yaml::from_str(yaml).map_err(|e| ApisixClientError::InvalidRequest(format!("invalid standalone configuration: {}", e)))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_yaml()?)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("unable to write {}: {}", path.display(), e)))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ApisixClientError::InvalidRequest(format!("unable to read {}: {}", path.display(), e)))?;
+        StandaloneConfig::from_yaml_str(&content)
+    }
+}
+
+impl TryFrom<DesiredState> for StandaloneConfig {
+    type Error = ApisixClientError;
+
+    fn try_from(state: DesiredState) -> Result<Self> {
+        Ok(StandaloneConfig {
+            ssls: raw(state.ssls)?,
+            protos: raw(state.protos)?,
+            upstreams: raw(state.upstreams)?,
+            services: raw(state.services)?,
+            plugin_configs: raw(state.plugin_configs)?,
+            global_rules: raw(state.global_rules)?,
+            consumer_groups: raw(state.consumer_groups)?,
+            consumers: raw(state.consumers)?,
+            routes: raw(state.routes)?,
+            stream_routes: raw(state.stream_routes)?,
+            plugin_metadata: None,
+            plugins: None,
+        })
+    }
+}
+
+/// Fails when an object is not modelled by this crate, see [StandaloneConfig::objects], or when the
+/// configuration holds `plugins` or `plugin_metadata`, which a desired state cannot hold
+impl TryFrom<StandaloneConfig> for DesiredState {
+    type Error = ApisixClientError;
+
+    fn try_from(config: StandaloneConfig) -> Result<Self> {
+        let unsupported: Vec<&str> = [("plugins", &config.plugins), ("plugin_metadata", &config.plugin_metadata)].into_iter()
+            .filter(|(_, items)| items.as_ref().is_some_and(|items| !items.is_empty()))
+            .map(|(name, _)| name)
+            .collect();
+        if !unsupported.is_empty() {
+            return Err(ApisixClientError::InvalidRequest(format!("not supported by a desired state: {}", unsupported.join(", "))));
+        }
+        Ok(DesiredState {
+            ssls: typed(ResourceKind::ssls, config.ssls)?,
+            protos: typed(ResourceKind::protos, config.protos)?,
+            upstreams: typed(ResourceKind::upstreams, config.upstreams)?,
+            services: typed(ResourceKind::services, config.services)?,
+            plugin_configs: typed(ResourceKind::plugin_configs, config.plugin_configs)?,
+            global_rules: typed(ResourceKind::global_rules, config.global_rules)?,
+            consumer_groups: typed(ResourceKind::consumer_groups, config.consumer_groups)?,
+            consumers: typed(ResourceKind::consumers, config.consumers)?,
+            routes: typed(ResourceKind::routes, config.routes)?,
+            stream_routes: typed(ResourceKind::stream_routes, config.stream_routes)?,
+        })
+    }
+}
+
+fn raw<T: Serialize>(items: Option<Vec<T>>) -> Result<Option<Vec<Value>>> {
+    items.map(|items| {
+        items.iter()
+            .map(|item| serde_json::to_value(item).map_err(|e| ApisixClientError::InvalidRequest(e.to_string())))
+            .collect()
+    }).transpose()
+}
+
+/// Request models of the raw objects, checked with [AdminObject::from_value]
+fn typed<T: DeserializeOwned>(kind: ResourceKind, items: Option<Vec<Value>>) -> Result<Option<Vec<T>>> {
+    items.map(|items| {
+        items.into_iter()
+            .map(|value| {
+                let value = AdminObject::from_value(kind, value)?.to_value()?;
+                serde_json::from_value(value).map_err(|e| ApisixClientError::InvalidRequest(format!("invalid {}: {}", kind, e)))
+            })
+            .collect()
+    }).transpose()
+}
+
+// region: tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RouteBuilder, UpstreamBuilder};
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_standalone_round_trip() {
+        let upstream = UpstreamBuilder::new().with_id("u1".to_string()).with_nodes(serde_json::json!({"127.0.0.1:1980": 1})).build().unwrap();
+        let route = RouteBuilder::new().with_id("r1".to_string()).with_uri("/hello".to_string())
+            .with_upstream_id("u1".to_string()).build().unwrap();
+        let config = StandaloneConfig::from_objects([AdminObject::from(route), AdminObject::from(upstream)]).unwrap();
+        assert!(config.services.is_none());
+        let yaml = config.to_yaml().unwrap();
+        assert!(yaml.ends_with("\n#END\n"));
+        assert!(yaml.contains("routes:"));
+        let parsed = StandaloneConfig::from_yaml_str(&yaml).unwrap();
+        assert_eq!(parsed, config);
+        assert_eq!(parsed.objects().unwrap()[0].kind(), ResourceKind::upstreams);
+
+        assert!(StandaloneConfig::from_yaml_str("routes: []\n").is_err());
+        assert_eq!(StandaloneConfig::from_yaml_str("#END\n").unwrap(), StandaloneConfig::default());
+        let with_plugins = StandaloneConfig::from_yaml_str("plugins:\n  - name: ip-restriction\n#END").unwrap();
+        assert_eq!(with_plugins.plugins.as_ref().unwrap().len(), 1);
+        assert!(DesiredState::try_from(with_plugins).is_err());
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_standalone_unmodelled_plugin() {
+        let yaml = "routes:\n  - id: r1\n    uri: /hello\n    plugins:\n      cors: { allow_origins: \"*\" }\n#END\n";
+        let config = StandaloneConfig::from_yaml_str(yaml).unwrap();
+        let parsed = StandaloneConfig::from_yaml_str(&config.to_yaml().unwrap()).unwrap();
+        assert_eq!(parsed.routes.unwrap()[0]["plugins"]["cors"]["allow_origins"], "*");
+        assert!(config.objects().is_err());
+        assert!(DesiredState::try_from(config).is_err());
+    }
+}
+// endregion: tests